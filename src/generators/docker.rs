@@ -0,0 +1,61 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::Transport;
+use super::{project_files::ProjectFiles, GeneratorOptions};
+
+/// Host the network transports listen on inside the container, so published ports reach them
+const CONTAINER_HOST: &str = "0.0.0.0";
+
+/// Writes the Dockerfile from the language-specific `build` instructions, followed by the
+/// selected transport's network settings and the command starting the server
+pub(crate) fn write_dockerfile(files: &ProjectFiles, options: &GeneratorOptions, build: &str, command: &[String]) -> Result<()> {
+    let network = match options.transport {
+        Transport::Stdio => String::new(),
+        _ => format!(
+            "\n# Listen on all interfaces so the published port reaches the server\nENV HOST={host}\nENV PORT={port}\nEXPOSE {port}\n",
+            host = CONTAINER_HOST,
+            port = options.port()
+        ),
+    };
+    
+    let command = command.iter()
+        .map(serde_json::to_string)
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    files.write("Dockerfile", format!("{}{}\nCMD [{}]\n", build, network, command.join(", ")))
+}
+
+/// README section on building and running the image, if a Dockerfile is generated
+pub(crate) fn docker_readme_section(options: &GeneratorOptions, project_name: &str) -> String {
+    if !options.docker {
+        return String::new();
+    }
+    
+    // Image names must be lowercase
+    let image = Path::new(project_name).file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_else(|| project_name.to_lowercase());
+    // The Dockerfile mounts the .npmrc as a secret instead of copying its credentials
+    let secret = if options.npmrc.is_some() { "--secret id=npmrc,src=.npmrc " } else { "" };
+    let (run, note) = match options.transport {
+        Transport::Stdio => (
+            format!("docker run -i --rm {}", image),
+            "`-i` keeps stdin open for the stdio transport, so MCP clients can also use `docker` as the server command with these arguments.".to_string(),
+        ),
+        _ => (
+            format!("docker run --rm -p {port}:{port} {}", image, port = options.port()),
+            format!("The server listens on port {} of the container, published on the same port of the host.", options.port()),
+        ),
+    };
+    
+    format!(r#"### Running in Docker
+
+```bash
+docker build {secret}-t {image} .
+{run}
+```
+
+{note}
+
+"#)
+}
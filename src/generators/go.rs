@@ -0,0 +1,692 @@
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::Tool;
+use crate::utils::license;
+use super::{configure_git_identity, confirm_commands, http_readme_section, inspector_readme_section, print_dependency_table, project_files::ProjectFiles, server_name_from_dir, write_inspector_config, write_license, write_summary, Generator, GeneratorOptions, DEFAULT_WEATHER_API};
+
+/// Default entrypoint file name
+pub const DEFAULT_ENTRYPOINT: &str = "main.go";
+
+/// Go module providing the MCP server implementation
+const MCP_GO_MODULE: &str = "github.com/mark3labs/mcp-go";
+
+/// Version of mcp-go required in go.mod
+const MCP_GO_VERSION: &str = "v0.32.0";
+
+pub struct GoGenerator {
+    project_name: String,
+    _tool: Tool,
+    project_path: PathBuf,
+    files: ProjectFiles,
+    options: GeneratorOptions,
+}
+
+impl Generator for GoGenerator {
+    fn new(project_name: &str, tool: &Tool, options: &GeneratorOptions) -> Self {
+        let project_path = options.project_path(project_name);
+        
+        Self {
+            project_name: project_name.to_string(),
+            _tool: tool.clone(),
+            files: ProjectFiles::new(project_path.clone(), options.dry_run, &options.line_endings),
+            project_path,
+            options: options.clone(),
+        }
+    }
+    
+    fn generate(&self) -> Result<()> {
+        // Create the project directory
+        self.create_directories()?;
+        
+        // Create project files
+        self.create_files()?;
+        
+        // Resolve the module dependencies
+        if !self.options.no_install {
+            self.init_package_manager()?;
+        }
+        
+        // Initialize git
+        if !self.options.no_git {
+            self.init_git()?;
+        }
+        
+        if self.files.is_dry_run() {
+            self.files.print_plan();
+        }
+        
+        if self.options.print_tree_after {
+            self.files.print_tree();
+        }
+        
+        if self.options.dependency_table {
+            print_dependency_table("go.mod", &[(MCP_GO_MODULE, MCP_GO_VERSION, "runtime")]);
+        }
+        
+        if let Some(path) = &self.options.summary_json {
+            write_summary(path, "go", &["go"], &self.files)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn create_directories(&self) -> Result<()> {
+        // Go projects keep everything in the module root
+        self.files.create_root(self.options.allow_existing_empty)
+    }
+    
+    fn create_files(&self) -> Result<()> {
+        // Create go.mod
+        self.create_go_mod()?;
+        
+        // Create .gitignore
+        self.create_gitignore()?;
+        
+        // Create LICENSE
+        write_license(&self.files, &self.options, &self.project_name)?;
+        
+        // Create .dockerignore
+        if self.options.with_dockerignore_only {
+            self.create_dockerignore()?;
+        }
+        
+        // Create main server file
+        self.create_server_file()?;
+        
+        // Create mcp-inspector.json
+        if self.options.with_inspector_config {
+            write_inspector_config(&self.files, self.options.server_name(), &self.launch_command())?;
+        }
+        
+        // Create README
+        self.create_readme()?;
+        
+        Ok(())
+    }
+    
+    fn init_package_manager(&self) -> Result<()> {
+        if self.options.frozen_lockfile {
+            return self.install_from_lockfile();
+        }
+        
+        let tidy_command = self.tidy_command();
+        
+        if self.files.is_dry_run() {
+            self.files.record_command(&tidy_command);
+            return Ok(());
+        }
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&tidy_command])? {
+            println!("⏭️  Skipping dependency resolution");
+            return Ok(());
+        }
+        
+        println!("📦 Resolving Go module dependencies...");
+        let output = Command::new(&tidy_command[0])
+            .args(&tidy_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to run go mod tidy")?;
+        
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            eprintln!("⚠️ Warning: Failed to resolve dependencies: {}", error);
+            eprintln!("Please run '{}' manually in the project directory", shell_words::join(&tidy_command));
+        } else {
+            println!("✅ Dependencies resolved successfully");
+        }
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    fn init_git(&self) -> Result<()> {
+        if self.files.is_dry_run() {
+            self.files.record_command(&["git".to_string(), "init".to_string()]);
+            return configure_git_identity(&self.files, &self.project_path, &self.options);
+        }
+        
+        Command::new("git")
+            .args(["init"])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to initialize git repository")?;
+        
+        configure_git_identity(&self.files, &self.project_path, &self.options)
+    }
+}
+
+impl GoGenerator {
+    /// Command starting the server from the project directory
+    fn launch_command(&self) -> Vec<String> {
+        vec!["go".to_string(), "run".to_string(), ".".to_string()]
+    }
+    
+    /// Entrypoint file name
+    fn entrypoint(&self) -> &str {
+        self.options.entrypoint_or(DEFAULT_ENTRYPOINT)
+    }
+    
+    /// Module path declared in go.mod, derived from the project directory
+    fn module_path(&self) -> String {
+        server_name_from_dir(&self.project_name)
+    }
+    
+    /// Command adding missing requirements and writing go.sum
+    fn tidy_command(&self) -> Vec<String> {
+        let mut command: Vec<String> = ["go", "mod", "tidy"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Downloads exactly the module versions go.sum pins with `go mod download`
+    fn install_from_lockfile(&self) -> Result<()> {
+        if !self.project_path.join("go.sum").exists() {
+            bail!("--frozen-lockfile needs an existing go.sum in the project");
+        }
+        
+        let mut download_command: Vec<String> = ["go", "mod", "download"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        download_command.extend(self.options.extra_install_args.iter().cloned());
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&download_command])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
+        println!("📦 Downloading Go modules pinned by go.sum...");
+        let output = Command::new(&download_command[0])
+            .args(&download_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to download dependencies with go mod download")?;
+        
+        if !output.status.success() {
+            bail!(
+                "'{}' failed:\n{}",
+                shell_words::join(&download_command),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        println!("✅ Dependencies installed successfully");
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks that the mcp-go server package resolves in the module
+    fn verify_sdk_resolves(&self) -> Result<()> {
+        println!("🔍 Verifying the MCP SDK can be imported...");
+        
+        let output = Command::new("go")
+            .args(["list", &format!("{}/server", MCP_GO_MODULE)])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to run go list to verify the MCP SDK")?;
+        
+        if !output.status.success() {
+            bail!(
+                "The MCP SDK does not resolve after installation:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        
+        println!("✅ MCP SDK resolves correctly");
+        Ok(())
+    }
+    
+    fn create_go_mod(&self) -> Result<()> {
+        let go_mod = format!(r#"module {}
+
+go 1.23
+
+require {} {}
+"#, self.module_path(), MCP_GO_MODULE, MCP_GO_VERSION);
+        
+        self.files.write("go.mod", go_mod)?;
+        
+        Ok(())
+    }
+    
+    fn create_gitignore(&self) -> Result<()> {
+        let gitignore = format!(r#"# Binaries
+/{}
+*.exe
+*.exe~
+*.dll
+*.so
+*.dylib
+
+# Test binaries and coverage
+*.test
+*.out
+coverage.*
+
+# Workspace files
+go.work
+go.work.sum
+
+# Environment variables
+.env
+.env.*
+
+# IDE
+.idea/
+.vscode/
+*.swp
+*.swo
+.DS_Store
+
+# MCP specific
+*.log
+mcp_debug_output/
+claude_config_backup.json
+"#, self.module_path());
+        let gitignore = self.options.gitignore.clone().unwrap_or(gitignore);
+        
+        self.files.write(".gitignore", gitignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_dockerignore(&self) -> Result<()> {
+        let dockerignore = format!(r#"# Binaries are built inside the image
+/{}
+*.exe
+*.test
+*.out
+
+# Local environment and secrets
+.env
+.env.*
+
+# Version control and editors
+.git/
+.gitignore
+.idea/
+.vscode/
+.DS_Store
+"#, self.module_path());
+        
+        self.files.write(".dockerignore", dockerignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_server_file(&self) -> Result<()> {
+        let server_code = [
+            SERVER_IMPORTS.to_string(),
+            NWS_HELPERS.to_string(),
+            self.weather_tools(),
+            self.server_main(),
+        ].join("\n");
+        let server_code = server_code.replace(DEFAULT_WEATHER_API, self.options.weather_api());
+        
+        self.files.write(self.entrypoint(), server_code)?;
+        
+        Ok(())
+    }
+    
+    /// Weather tools, reporting failures as isError results when requested
+    fn weather_tools(&self) -> String {
+        if self.options.tool_error_handling {
+            WEATHER_TOOLS.replace(
+                "return mcp.NewToolResultText(message)",
+                "return mcp.NewToolResultError(message)",
+            )
+        } else {
+            WEATHER_TOOLS.to_string()
+        }
+    }
+    
+    /// Server construction and stdio loop using the configured server name
+    fn server_main(&self) -> String {
+        SERVER_MAIN.replace(
+            "server.NewMCPServer(\"weather\",",
+            &format!("server.NewMCPServer({},", go_string(self.options.server_name())),
+        )
+    }
+    
+    fn create_readme(&self) -> Result<()> {
+        let binary = self.module_path();
+        
+        let integration = if self.options.skip_readme_integration {
+            String::new()
+        } else {
+            format!(r#"For Claude for Desktop integration, you'll need to add the server to your Claude configuration. Open `~/Library/Application Support/Claude/claude_desktop_config.json` and add:
+
+```json
+{{
+  "mcpServers": {{
+    {}: {{
+      "command": "/ABSOLUTE/PATH/TO/{}/{}"
+    }}
+  }}
+}}
+```
+
+Replace `/ABSOLUTE/PATH/TO/{}` with the absolute path to your project.
+
+"#, go_string(self.options.server_name()), self.options.directory_name(&self.project_name), binary, self.options.directory_name(&self.project_name))
+        };
+        
+        let integration = integration + &http_readme_section(&self.options) + &inspector_readme_section(&self.options);
+        
+        let readme = format!(r#"# {}
+
+A Model Context Protocol (MCP) server implementation.
+
+## About
+
+This project implements an MCP server that provides weather information via the National Weather Service API. It is built with [mcp-go](https://{}) and can be used with MCP compatible clients like Claude for Desktop.
+
+## Getting Started
+
+### Prerequisites
+
+- Go 1.23 or newer
+
+### Installation
+
+```bash
+# Resolve dependencies
+go mod tidy
+```
+
+### Building the Server
+
+```bash
+go build -o {} .
+```
+
+### Running the Server
+
+For development:
+```bash
+go run .
+```
+
+{}## Available Tools
+
+This MCP server provides the following tools:
+
+- **get-alerts**: Get active weather alerts for a US state
+  - Parameters: `state` (two-letter state code)
+
+- **get-forecast**: Get weather forecast for a location
+  - Parameters: `latitude`, `longitude`
+
+## Example Queries for Claude
+
+After connecting your server to Claude for Desktop, you can ask questions like:
+
+- "What's the weather in Sacramento?"
+- "What are the active weather alerts in California?"
+- "Tell me the forecast for New York (40.7128, -74.0060)"
+{}"#,
+            self.project_name,
+            MCP_GO_MODULE,
+            binary,
+            integration,
+            license::readme_section(&self.options.license)
+        );
+        
+        self.files.write("README.md", readme)?;
+        
+        Ok(())
+    }
+}
+
+/// Go string literal for a value (JSON escapes are valid Go escapes)
+fn go_string(value: &str) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+/// Package clause and imports of the server file
+const SERVER_IMPORTS: &str = r#"package main
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/mark3labs/mcp-go/mcp"
+	"github.com/mark3labs/mcp-go/server"
+)
+"#;
+
+/// NWS API constants, request helper, response types and alert formatting
+const NWS_HELPERS: &str = r#"const (
+	nwsAPIBase = "https://api.weather.gov"
+	userAgent  = "weather-app/1.0"
+)
+
+var httpClient = &http.Client{Timeout: 30 * time.Second}
+
+// makeNWSRequest fetches a URL from the NWS API and decodes the JSON response into target
+func makeNWSRequest(ctx context.Context, url string, target any) error {
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, url, nil)
+	if err != nil {
+		return err
+	}
+	req.Header.Set("User-Agent", userAgent)
+	req.Header.Set("Accept", "application/geo+json")
+
+	resp, err := httpClient.Do(req)
+	if err != nil {
+		return err
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return fmt.Errorf("HTTP error! status: %d", resp.StatusCode)
+	}
+	return json.NewDecoder(resp.Body).Decode(target)
+}
+
+type alertFeature struct {
+	Properties struct {
+		Event       string `json:"event"`
+		AreaDesc    string `json:"areaDesc"`
+		Severity    string `json:"severity"`
+		Description string `json:"description"`
+		Instruction string `json:"instruction"`
+	} `json:"properties"`
+}
+
+type alertsResponse struct {
+	Features []alertFeature `json:"features"`
+}
+
+type pointsResponse struct {
+	Properties struct {
+		Forecast string `json:"forecast"`
+	} `json:"properties"`
+}
+
+type forecastPeriod struct {
+	Name             string   `json:"name"`
+	Temperature      *float64 `json:"temperature"`
+	TemperatureUnit  string   `json:"temperatureUnit"`
+	WindSpeed        string   `json:"windSpeed"`
+	WindDirection    string   `json:"windDirection"`
+	DetailedForecast string   `json:"detailedForecast"`
+}
+
+type forecastResponse struct {
+	Properties struct {
+		Periods []forecastPeriod `json:"periods"`
+	} `json:"properties"`
+}
+
+// orDefault returns value, or fallback when value is empty
+func orDefault(value, fallback string) string {
+	if value == "" {
+		return fallback
+	}
+	return value
+}
+
+// formatAlert formats an alert feature into a readable string
+func formatAlert(feature alertFeature) string {
+	props := feature.Properties
+	description := []rune(props.Description)
+	if len(description) > 200 {
+		description = description[:200]
+	}
+	return strings.Join([]string{
+		"Event: " + orDefault(props.Event, "Unknown"),
+		"Area: " + orDefault(props.AreaDesc, "Unknown"),
+		"Severity: " + orDefault(props.Severity, "Unknown"),
+		"Description: " + orDefault(string(description), "No description") + "...",
+		"Instructions: " + orDefault(props.Instruction, "No specific instructions provided"),
+		"---",
+	}, "\n")
+}
+"#;
+
+/// Weather tool handlers
+const WEATHER_TOOLS: &str = r#"// toolFailure reports a failed tool call to the client
+func toolFailure(message string) *mcp.CallToolResult {
+	return mcp.NewToolResultText(message)
+}
+
+func getAlerts(ctx context.Context, request mcp.CallToolRequest) (*mcp.CallToolResult, error) {
+	state, err := request.RequireString("state")
+	if err != nil {
+		return mcp.NewToolResultError(err.Error()), nil
+	}
+	stateCode := strings.ToUpper(state)
+
+	var alerts alertsResponse
+	alertsURL := fmt.Sprintf("%s/alerts/active/area/%s", nwsAPIBase, stateCode)
+	if err := makeNWSRequest(ctx, alertsURL, &alerts); err != nil {
+		fmt.Fprintln(os.Stderr, "Error making NWS request:", err)
+		return toolFailure("Failed to retrieve alerts data"), nil
+	}
+
+	if len(alerts.Features) == 0 {
+		return mcp.NewToolResultText("No active alerts for " + stateCode), nil
+	}
+
+	formattedAlerts := make([]string, 0, len(alerts.Features))
+	for _, feature := range alerts.Features {
+		formattedAlerts = append(formattedAlerts, formatAlert(feature))
+	}
+	alertsText := fmt.Sprintf("Active alerts for %s:\n\n%s", stateCode, strings.Join(formattedAlerts, "\n"))
+
+	return mcp.NewToolResultText(alertsText), nil
+}
+
+func getForecast(ctx context.Context, request mcp.CallToolRequest) (*mcp.CallToolResult, error) {
+	latitude, err := request.RequireFloat("latitude")
+	if err != nil {
+		return mcp.NewToolResultError(err.Error()), nil
+	}
+	longitude, err := request.RequireFloat("longitude")
+	if err != nil {
+		return mcp.NewToolResultError(err.Error()), nil
+	}
+
+	// Get grid point data
+	var points pointsResponse
+	pointsURL := fmt.Sprintf("%s/points/%.4f,%.4f", nwsAPIBase, latitude, longitude)
+	if err := makeNWSRequest(ctx, pointsURL, &points); err != nil {
+		fmt.Fprintln(os.Stderr, "Error making NWS request:", err)
+		return toolFailure(fmt.Sprintf("Failed to retrieve grid point data for coordinates: %g, %g. This location may not be supported by the NWS API (only US locations are supported).", latitude, longitude)), nil
+	}
+
+	forecastURL := points.Properties.Forecast
+	if forecastURL == "" {
+		return toolFailure("Failed to get forecast URL from grid point data"), nil
+	}
+
+	// Get forecast data
+	var forecast forecastResponse
+	if err := makeNWSRequest(ctx, forecastURL, &forecast); err != nil {
+		fmt.Fprintln(os.Stderr, "Error making NWS request:", err)
+		return toolFailure("Failed to retrieve forecast data"), nil
+	}
+
+	periods := forecast.Properties.Periods
+	if len(periods) == 0 {
+		return toolFailure("No forecast periods available"), nil
+	}
+	if len(periods) > 5 {
+		periods = periods[:5]
+	}
+
+	// Format forecast periods
+	formattedForecast := make([]string, 0, len(periods))
+	for _, period := range periods {
+		temperature := "Unknown"
+		if period.Temperature != nil {
+			temperature = fmt.Sprintf("%g", *period.Temperature)
+		}
+		formattedForecast = append(formattedForecast, strings.Join([]string{
+			orDefault(period.Name, "Unknown") + ":",
+			fmt.Sprintf("Temperature: %s°%s", temperature, orDefault(period.TemperatureUnit, "F")),
+			fmt.Sprintf("Wind: %s %s", orDefault(period.WindSpeed, "Unknown"), period.WindDirection),
+			"Forecast: " + orDefault(period.DetailedForecast, "No forecast available"),
+			"---",
+		}, "\n"))
+	}
+	forecastText := fmt.Sprintf("Forecast for %g, %g:\n\n%s", latitude, longitude, strings.Join(formattedForecast, "\n"))
+
+	return mcp.NewToolResultText(forecastText), nil
+}
+"#;
+
+/// Server construction, tool registration and the stdio loop
+const SERVER_MAIN: &str = r#"func main() {
+	// Create server instance
+	s := server.NewMCPServer("weather", "1.0.0", server.WithToolCapabilities(false))
+
+	s.AddTool(mcp.NewTool("get-alerts",
+		mcp.WithDescription("Get weather alerts for a state"),
+		mcp.WithString("state",
+			mcp.Required(),
+			mcp.Description("Two-letter state code (e.g. CA, NY)"),
+		),
+	), getAlerts)
+
+	s.AddTool(mcp.NewTool("get-forecast",
+		mcp.WithDescription("Get weather forecast for a location"),
+		mcp.WithNumber("latitude",
+			mcp.Required(),
+			mcp.Min(-90),
+			mcp.Max(90),
+			mcp.Description("Latitude of the location"),
+		),
+		mcp.WithNumber("longitude",
+			mcp.Required(),
+			mcp.Min(-180),
+			mcp.Max(180),
+			mcp.Description("Longitude of the location"),
+		),
+	), getForecast)
+
+	fmt.Fprintln(os.Stderr, "Weather MCP Server running on stdio")
+	if err := server.ServeStdio(s); err != nil {
+		fmt.Fprintln(os.Stderr, "Fatal error in main():", err)
+		os.Exit(1)
+	}
+}
+"#;
@@ -1,13 +1,936 @@
+pub mod docker;
+pub mod go;
+pub mod project_files;
 pub mod python;
+pub mod rust;
 pub mod typescript;
 
-use anyhow::Result;
-use crate::Tool;
+use anyhow::{bail, Context, Result};
+use reqwest::Url;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::SystemTime;
+use crate::{Ci, Cli, Language, License, LineEndings, Template, Tool, Transport, TsModuleType, YarnLinker};
+use crate::utils::archive::ArchiveFormat;
+use crate::utils::openapi::{load_openapi_tools, OpenApiTools};
+use crate::utils::dependency_checker::detect_version;
+use crate::utils::license::license_text;
+use crate::utils::source::read_source;
+use project_files::ProjectFiles;
+
+/// Base URL of the National Weather Service API used by the weather example
+pub const DEFAULT_WEATHER_API: &str = "https://api.weather.gov";
+
+/// Server name used when it does not follow the project directory
+pub const DEFAULT_SERVER_NAME: &str = "weather";
+
+/// Port network transports listen on unless --port is given
+pub const DEFAULT_PORT: u16 = 8080;
+
+/// Port the Streamable HTTP transport listens on unless --port is given
+pub const DEFAULT_HTTP_PORT: u16 = 3000;
+
+/// Options that tweak what a generator produces
+#[derive(Debug, Clone, Default)]
+pub struct GeneratorOptions {
+    /// Directory the project directory is created in (default: the current directory)
+    pub output_dir: Option<PathBuf>,
+    /// Project directory, when it differs from the project name
+    pub path: Option<PathBuf>,
+    /// Extra arguments appended to the package manager install command
+    pub extra_install_args: Vec<String>,
+    /// Author name written into the manifests
+    pub author: Option<String>,
+    /// Author email written into the manifests
+    pub email: Option<String>,
+    /// License of the generated project
+    pub license: License,
+    /// Module system for TypeScript projects
+    pub ts_module_type: TsModuleType,
+    /// Yarn Berry linker for Yarn projects
+    pub yarn_linker: YarnLinker,
+    /// Name the MCP server identifies itself with
+    pub server_name: Option<String>,
+    /// Example code the server starts from
+    pub template: Template,
+    /// Transport the generated server listens on
+    pub transport: Transport,
+    /// Port for network transports
+    pub port: Option<u16>,
+    /// Base URL substituted for the NWS API in the weather example
+    pub weather_api: Option<String>,
+    /// Omit the Claude for Desktop integration section from the README
+    pub skip_readme_integration: bool,
+    /// Report example tool failures as isError results
+    pub tool_error_handling: bool,
+    /// Add an example tool registered only when its API key is configured
+    pub with_graceful_degradation: bool,
+    /// Add an example tool demonstrating richer input validation
+    pub with_input_validation_examples: bool,
+    /// Add two example tools sharing a forecast helper
+    pub with_multi_tool_example: bool,
+    /// Register a sample resource alongside the tools
+    pub with_resources: bool,
+    /// Serve resources/list a page at a time
+    pub with_pagination_example: bool,
+    /// Register a sample prompt template alongside the tools
+    pub with_prompts: bool,
+    /// Add a prompt with an autocompleted argument
+    pub with_completion_example: bool,
+    /// Add a test setup with an example test
+    pub with_tests: bool,
+    /// Add an ESLint setup to TypeScript projects
+    pub with_lint: bool,
+    /// Split the generated server into multiple modules
+    pub with_multi_file: bool,
+    /// Import local TypeScript modules through the `@/*` path alias
+    pub with_typescript_path_aliases: bool,
+    /// Also generate a QUICKSTART.md with copy-paste commands
+    pub readme_quickstart_only: bool,
+    /// Use only the standard library for HTTP in Python projects
+    pub minimal_deps: bool,
+    /// Reuse one httpx client for the Python NWS requests
+    pub python_httpx_client_reuse: bool,
+    /// Type the Python NWS responses with TypedDicts
+    pub python_typing_strict: bool,
+    /// Validate the TypeScript NWS responses with zod schemas
+    pub with_graceful_json_parse: bool,
+    /// Remove the comments from the generated server code
+    pub strip_comments: bool,
+    /// Expose the Python server's `main()` as a console script
+    pub python_entry_console_script: bool,
+    /// Custom file name for the server entrypoint
+    pub entrypoint: Option<String>,
+    /// Tools generated from an OpenAPI spec
+    pub openapi_tools: Option<OpenApiTools>,
+    /// Fail if the MCP SDK cannot be imported after installing dependencies
+    pub verify_sdk_resolves: bool,
+    /// Print what would be generated instead of writing files or running commands
+    pub dry_run: bool,
+    /// Reuse the project directory if it already exists (checked to be effectively empty)
+    pub allow_existing_empty: bool,
+    /// Newline style of the generated files
+    pub line_endings: LineEndings,
+    /// Ask for confirmation before running package manager commands
+    pub confirm_install_commands: bool,
+    /// Where to write a JSON report of the generation
+    pub summary_json: Option<PathBuf>,
+    /// Install from the existing lockfile without updating it
+    pub frozen_lockfile: bool,
+    /// Skip installing dependencies, leaving that to the user
+    pub no_install: bool,
+    /// Skip `git init` in the new project
+    pub no_git: bool,
+    /// Repository-local git user.name
+    pub git_user: Option<String>,
+    /// Repository-local git user.email
+    pub git_email: Option<String>,
+    /// Declare the server capabilities explicitly
+    pub server_capabilities: bool,
+    /// Write an MCP Inspector config launching the server
+    pub with_inspector_config: bool,
+    /// Trace tool calls with OpenTelemetry
+    pub with_otel: bool,
+    /// Print the dependency versions written into the manifests
+    pub dependency_table: bool,
+    /// Print a tree of the generated files
+    pub print_tree_after: bool,
+    /// Generate a Dockerfile and .dockerignore
+    pub docker: bool,
+    /// CI service to generate a workflow for
+    pub ci: Option<Ci>,
+    /// Generate a .dockerignore without a Dockerfile
+    pub with_dockerignore_only: bool,
+    /// Custom .gitignore content replacing the built-in one
+    pub gitignore: Option<String>,
+    /// .npmrc copied into TypeScript projects before installing
+    pub npmrc: Option<PathBuf>,
+    /// Put the Rust server in a member crate of a Cargo workspace
+    pub cargo_workspace: bool,
+}
+
+impl GeneratorOptions {
+    /// Builds generator options from the parsed CLI arguments
+    pub fn from_cli(cli: &Cli) -> Result<Self> {
+        // Path aliases only matter once the TypeScript server has local modules to import
+        let with_typescript_path_aliases = cli.with_typescript_path_aliases
+            && matches!(cli.language, Language::Typescript | Language::Ts);
+        // The TypeScript tests import the helpers, which need a module of their own
+        let with_tests_ts = cli.with_tests && matches!(cli.language, Language::Typescript | Language::Ts);
+        let with_multi_file = cli.with_multi_file || with_typescript_path_aliases || with_tests_ts;
+        
+        let extra_install_args = match &cli.extra_install_args {
+            Some(args) => shell_words::split(args)
+                .context(format!("Failed to parse --extra-install-args: {}", args))?,
+            None => Vec::new(),
+        };
+        
+        let weather_api = match &cli.weather_api {
+            Some(url) => Some(validate_weather_api(url)?),
+            None => None,
+        };
+        
+        let entrypoint = match &cli.entrypoint {
+            Some(name) => Some(validate_entrypoint(name, &cli.language, with_multi_file)?),
+            None => None,
+        };
+        
+        // Checked before the spec is loaded, so an unsupported language is reported first
+        if cli.with_openapi_tool.is_some() && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-openapi-tool is only supported for TypeScript and Python projects");
+        }
+        let openapi_tools = match &cli.with_openapi_tool {
+            Some(source) => Some(load_openapi_tools(source)?),
+            None => None,
+        };
+        
+        let server_name = if cli.server_name_from_dir {
+            let directory = match &cli.path {
+                Some(path) => path.to_string_lossy().to_string(),
+                None => cli.project_name.clone().unwrap_or_default(),
+            };
+            Some(server_name_from_dir(&directory))
+        } else {
+            None
+        };
+        
+        if matches!(cli.language, Language::Go | Language::Golang | Language::Rust) && !matches!(cli.transport, Transport::Stdio) {
+            bail!("Go and Rust projects only support the stdio transport");
+        }
+        
+        if matches!(cli.template, Template::Minimal) {
+            if matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+                bail!("--template minimal is only available for TypeScript and Python projects");
+            }
+            let weather_flags = [
+                (cli.weather_api.is_some(), "--weather-api"),
+                (cli.tool_error_handling, "--tool-error-handling"),
+                (cli.with_graceful_degradation, "--with-graceful-degradation"),
+                (cli.with_input_validation_examples, "--with-input-validation-examples"),
+                (cli.with_multi_tool_example, "--with-multi-tool-example"),
+                (cli.with_completion_example, "--with-completion-example"),
+                (cli.with_typescript_path_aliases, "--with-typescript-path-aliases"),
+                (cli.with_tests, "--with-tests"),
+                (cli.with_multi_file, "--with-multi-file"),
+                (cli.python_typing_strict, "--python-typing-strict"),
+                (cli.with_graceful_json_parse, "--with-graceful-json-parse"),
+                (cli.python_httpx_client_reuse, "--python-httpx-client-reuse"),
+            ];
+            if let Some((_, flag)) = weather_flags.iter().find(|(set, _)| *set) {
+                bail!("{} extends the weather example and cannot be combined with --template minimal", flag);
+            }
+        }
+        
+        if cli.with_graceful_degradation && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-graceful-degradation is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_multi_file && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-multi-file is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.readme_quickstart_only && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--readme-quickstart-only is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_otel && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-otel is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.strip_comments && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--strip-comments is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_graceful_json_parse && !matches!(cli.language, Language::Typescript | Language::Ts) {
+            bail!("--with-graceful-json-parse is only supported for TypeScript projects");
+        }
+        
+        if cli.python_httpx_client_reuse {
+            if !matches!(cli.language, Language::Python | Language::Py) {
+                bail!("--python-httpx-client-reuse is only supported for Python projects");
+            }
+            if cli.minimal_deps {
+                bail!("--python-httpx-client-reuse shares an httpx client, which --minimal-deps replaces with urllib");
+            }
+        }
+        
+        if cli.python_typing_strict && !matches!(cli.language, Language::Python | Language::Py) {
+            bail!("--python-typing-strict is only supported for Python projects");
+        }
+        
+        if cli.python_entry_console_script && !matches!(cli.language, Language::Python | Language::Py) {
+            bail!("--python-entry-console-script is only supported for Python projects");
+        }
+        
+        if cli.minimal_deps && !matches!(cli.language, Language::Python | Language::Py) {
+            bail!("--minimal-deps is only supported for Python projects");
+        }
+        
+        if cli.ci.is_some() && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--ci is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_tests {
+            if matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+                bail!("--with-tests is only supported for TypeScript and Python projects");
+            }
+            if matches!(cli.tool, Some(Tool::Deno)) {
+                bail!("--with-tests sets up Vitest, which needs a Node.js package manager instead of Deno");
+            }
+        }
+        
+        if cli.with_lint {
+            if matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+                bail!("--with-lint is only supported for TypeScript and Python projects");
+            }
+            if matches!(cli.tool, Some(Tool::Deno)) {
+                bail!("--with-lint sets up ESLint, which needs a Node.js package manager; Deno projects can use `deno lint`");
+            }
+        }
+        
+        if cli.docker {
+            if matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+                bail!("--docker is only supported for TypeScript and Python projects");
+            }
+            if matches!(cli.tool, Some(Tool::Bun | Tool::Deno)) {
+                bail!("--docker builds TypeScript projects with Node.js and supports pnpm, yarn and npm");
+            }
+        }
+        
+        if cli.with_input_validation_examples && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-input-validation-examples is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_multi_tool_example && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-multi-tool-example is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_resources && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-resources is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_pagination_example && !cli.with_resources {
+            bail!("--with-pagination-example pages through the resources and needs --with-resources");
+        }
+        
+        if cli.with_prompts && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--with-prompts is only supported for TypeScript and Python projects");
+        }
+        
+        if cli.with_completion_example && !cli.with_prompts {
+            bail!("--with-completion-example completes a prompt argument and needs --with-prompts");
+        }
+        
+        if cli.server_capabilities && matches!(cli.language, Language::Go | Language::Golang | Language::Rust) {
+            bail!("--server-capabilities is only supported for TypeScript and Python projects; Go and Rust servers always declare their capabilities");
+        }
+        
+        if cli.cargo_workspace && !matches!(cli.language, Language::Rust) {
+            bail!("--cargo-workspace is only supported for Rust projects");
+        }
+        
+        if cli.with_inspector_config && !matches!(cli.transport, Transport::Stdio) {
+            bail!("--with-inspector-config launches the server over stdio and needs --transport stdio");
+        }
+        
+        if cli.yarn_linker.is_some() && !matches!(cli.tool, Some(Tool::Yarn)) {
+            bail!("--yarn-linker only applies to Yarn projects (--tool yarn)");
+        }
+        
+        if cli.port.is_some() && matches!(cli.transport, Transport::Stdio) {
+            bail!("--port requires a network transport (e.g. --transport ws)");
+        }
+        
+        // Fall back to the git identity so the manifests name the actual author
+        let author = cli.author.clone().or_else(|| git_config("user.name"));
+        let email = cli.email.clone().or_else(|| git_config("user.email"));
+        
+        let gitignore = match &cli.gitignore_from {
+            Some(source) => Some(read_source(source, ".gitignore")?),
+            None => None,
+        };
+        
+        if cli.dry_run && cli.deps_only {
+            bail!("--dry-run previews a new project; use --confirm-install-commands to review what --deps-only would run");
+        }
+        
+        if cli.print_next_steps_only && (cli.deps_only || cli.dry_run) {
+            bail!("--print-next-steps-only only prints guidance and cannot be combined with --deps-only or --dry-run");
+        }
+        
+        if cli.run_after_generate && (cli.dry_run || cli.deps_only || cli.print_next_steps_only) {
+            bail!("--run-after-generate starts a newly generated project and cannot be combined with --dry-run, --deps-only or --print-next-steps-only");
+        }
+        
+        if (cli.git_user.is_some() || cli.git_email.is_some()) && (cli.no_git || cli.output_archive.is_some()) {
+            bail!("--git-user and --git-email configure the new repository and cannot be combined with --no-git or --output-archive");
+        }
+        
+        if cli.no_install && cli.deps_only {
+            bail!("--no-install skips the installation that --deps-only performs");
+        }
+        
+        if cli.no_install && cli.verify_sdk_resolves {
+            bail!("--verify-sdk-resolves checks the installed dependencies and cannot be combined with --no-install");
+        }
+        
+        if let Some(archive) = &cli.output_archive {
+            ArchiveFormat::from_path(archive)?;
+            if cli.dry_run || cli.deps_only || cli.print_next_steps_only || cli.run_after_generate || cli.verify_sdk_resolves {
+                bail!("--output-archive packs a new project without installing it and cannot be combined with --dry-run, --deps-only, --print-next-steps-only, --run-after-generate or --verify-sdk-resolves");
+            }
+            if archive.exists() {
+                bail!("Archive '{}' already exists", archive.display());
+            }
+        }
+        
+        if cli.summary_json.is_some() && (cli.dry_run || cli.deps_only) {
+            bail!("--summary-json reports a generated project and cannot be combined with --dry-run or --deps-only");
+        }
+        
+        if cli.path.is_some() && cli.output_archive.is_some() {
+            bail!("--path sets the project directory and cannot be combined with --output-archive, which packs the project under its name");
+        }
+        
+        if cli.rename_on_conflict && (cli.deps_only || cli.print_next_steps_only || cli.output_archive.is_some()) {
+            bail!("--rename-on-conflict picks a new project directory and cannot be combined with --deps-only, --print-next-steps-only or --output-archive");
+        }
+        
+        if cli.frozen_lockfile && !cli.deps_only {
+            bail!("--frozen-lockfile requires --deps-only, since a newly generated project has no lockfile yet");
+        }
+        
+        if cli.detect_package_manager && !cli.deps_only {
+            bail!("--detect-package-manager requires --deps-only, since a newly generated project has no lockfile yet");
+        }
+        
+        if let Some(npmrc) = &cli.npmrc {
+            if !matches!(cli.language, Language::Typescript | Language::Ts) {
+                bail!("--npmrc is only supported for TypeScript projects");
+            }
+            if !npmrc.is_file() {
+                bail!("--npmrc file not found: {}", npmrc.display());
+            }
+        }
+        
+        Ok(Self {
+            output_dir: None,
+            path: cli.path.clone(),
+            extra_install_args,
+            author,
+            email,
+            license: cli.license.clone(),
+            ts_module_type: cli.ts_module_type.clone(),
+            yarn_linker: cli.yarn_linker.clone().unwrap_or_default(),
+            server_name,
+            template: cli.template.clone(),
+            transport: cli.transport.clone(),
+            port: cli.port,
+            weather_api,
+            skip_readme_integration: cli.skip_readme_integration,
+            tool_error_handling: cli.tool_error_handling,
+            with_graceful_degradation: cli.with_graceful_degradation,
+            with_input_validation_examples: cli.with_input_validation_examples,
+            with_multi_tool_example: cli.with_multi_tool_example,
+            with_resources: cli.with_resources,
+            with_pagination_example: cli.with_pagination_example,
+            with_prompts: cli.with_prompts,
+            with_completion_example: cli.with_completion_example,
+            with_tests: cli.with_tests,
+            with_lint: cli.with_lint,
+            with_multi_file,
+            with_typescript_path_aliases,
+            readme_quickstart_only: cli.readme_quickstart_only,
+            minimal_deps: cli.minimal_deps,
+            python_httpx_client_reuse: cli.python_httpx_client_reuse,
+            python_typing_strict: cli.python_typing_strict,
+            with_graceful_json_parse: cli.with_graceful_json_parse,
+            strip_comments: cli.strip_comments,
+            python_entry_console_script: cli.python_entry_console_script,
+            entrypoint,
+            openapi_tools,
+            verify_sdk_resolves: cli.verify_sdk_resolves,
+            dry_run: cli.dry_run,
+            allow_existing_empty: cli.allow_existing_empty,
+            line_endings: cli.line_endings.clone(),
+            confirm_install_commands: cli.confirm_install_commands,
+            summary_json: cli.summary_json.clone(),
+            frozen_lockfile: cli.frozen_lockfile,
+            // Archives are packed from a temporary directory, so git and installs would be wasted
+            no_install: cli.no_install || cli.output_archive.is_some(),
+            no_git: cli.no_git || cli.output_archive.is_some(),
+            git_user: cli.git_user.clone(),
+            git_email: cli.git_email.clone(),
+            server_capabilities: cli.server_capabilities,
+            with_inspector_config: cli.with_inspector_config,
+            with_otel: cli.with_otel,
+            dependency_table: cli.dependency_table,
+            print_tree_after: cli.print_tree_after,
+            docker: cli.docker,
+            ci: cli.ci.clone(),
+            with_dockerignore_only: cli.with_dockerignore_only,
+            gitignore,
+            npmrc: cli.npmrc.clone(),
+            cargo_workspace: cli.cargo_workspace,
+        })
+    }
+    
+    /// Returns the weather API base URL, falling back to the NWS default
+    pub fn weather_api(&self) -> &str {
+        self.weather_api.as_deref().unwrap_or(DEFAULT_WEATHER_API)
+    }
+    
+    /// Returns the MCP server name, falling back to the fixed default
+    pub fn server_name(&self) -> &str {
+        self.server_name.as_deref().unwrap_or(DEFAULT_SERVER_NAME)
+    }
+    
+    /// Returns the port for network transports, falling back to the transport's default
+    pub fn port(&self) -> u16 {
+        let default = match self.transport {
+            Transport::Http => DEFAULT_HTTP_PORT,
+            _ => DEFAULT_PORT,
+        };
+        self.port.unwrap_or(default)
+    }
+    
+    /// MCP capabilities the generated server implements, as named in the initialize result
+    pub fn capabilities(&self) -> Vec<&'static str> {
+        let mut capabilities = vec!["tools"];
+        if self.with_resources {
+            capabilities.push("resources");
+        }
+        if self.with_prompts {
+            capabilities.push("prompts");
+        }
+        if self.with_completion_example {
+            capabilities.push("completions");
+        }
+        capabilities
+    }
+    
+    /// Returns the author as `Name <email>` (or just the name), if the name is known
+    pub fn author(&self) -> Option<String> {
+        let name = self.author.as_deref()?;
+        Some(match &self.email {
+            Some(email) => format!("{} <{}>", name, email),
+            None => name.to_string(),
+        })
+    }
+    
+    /// Returns the path of the project directory (`--path`, or else the project name),
+    /// inside the output directory if one is set
+    pub fn project_path(&self, project_name: &str) -> PathBuf {
+        let directory = self.path.clone().unwrap_or_else(|| PathBuf::from(project_name));
+        match &self.output_dir {
+            Some(dir) => dir.join(directory),
+            None => directory,
+        }
+    }
+    
+    /// Project directory as given on the command line (`--path`, or else the project name)
+    pub fn directory(&self, project_name: &str) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => project_name.to_string(),
+        }
+    }
+    
+    /// Name of the project directory shown in the READMEs' placeholder paths
+    pub fn directory_name(&self, project_name: &str) -> String {
+        match self.path.as_ref().and_then(|path| path.file_name()) {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => project_name.to_string(),
+        }
+    }
+    
+    /// Returns the entrypoint file name, falling back to the generator's default
+    pub fn entrypoint_or<'a>(&'a self, default: &'a str) -> &'a str {
+        self.entrypoint.as_deref().unwrap_or(default)
+    }
+}
+
+/// Reads a value from the user's git config, if git is installed and the key is set
+fn git_config(key: &str) -> Option<String> {
+    let output = Command::new("git").args(["config", "--get", key]).output().ok()?;
+    let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !value.is_empty()).then_some(value)
+}
+
+/// Derives the server name from the last component of the project path
+fn server_name_from_dir(project_name: &str) -> String {
+    let path = Path::new(project_name);
+    let dir_name = match path.file_name() {
+        Some(name) => name.to_string_lossy().to_string(),
+        // "." or ".." name the directory only once resolved
+        None => path.canonicalize()
+            .ok()
+            .and_then(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .unwrap_or_default(),
+    };
+    
+    sanitize_server_name(&dir_name)
+}
+
+/// Turns a name into a valid MCP server name: lowercase, with runs of anything
+/// other than ASCII letters, digits, `-` and `_` collapsed into a single `-`
+pub fn sanitize_server_name(name: &str) -> String {
+    let mut sanitized = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            sanitized.push(c.to_ascii_lowercase());
+        } else if !sanitized.is_empty() && !sanitized.ends_with('-') {
+            sanitized.push('-');
+        }
+    }
+    
+    let sanitized = sanitized.trim_end_matches('-');
+    if sanitized.is_empty() {
+        DEFAULT_SERVER_NAME.to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+/// Validates a weather API base URL and strips any trailing slash
+fn validate_weather_api(url: &str) -> Result<String> {
+    let parsed = Url::parse(url).context(format!("Invalid --weather-api URL: {}", url))?;
+    if !matches!(parsed.scheme(), "http" | "https") || !parsed.has_host() {
+        bail!("Invalid --weather-api URL: {} (expected an http or https URL)", url);
+    }
+    
+    Ok(url.trim_end_matches('/').to_string())
+}
+
+/// Validates an entrypoint file name for the language, adding the extension if missing
+fn validate_entrypoint(name: &str, language: &Language, with_multi_file: bool) -> Result<String> {
+    let extension = match language {
+        Language::Python | Language::Py => "py",
+        Language::Typescript | Language::Ts => "ts",
+        Language::Go | Language::Golang => "go",
+        Language::Rust => "rs",
+    };
+    
+    let (stem, file_name) = match name.rsplit_once('.') {
+        Some((stem, ext)) if ext == extension => (stem, name.to_string()),
+        Some((_, ext)) => bail!("Invalid --entrypoint '{}': expected a .{} file, not .{}", name, extension, ext),
+        None => (name, format!("{}.{}", name, extension)),
+    };
+    
+    let valid_stem = match language {
+        // Python entrypoints must be importable module names, Rust ones valid binary paths
+        Language::Python | Language::Py | Language::Rust => {
+            stem.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !stem.starts_with(|c: char| c.is_ascii_digit())
+        },
+        Language::Typescript | Language::Ts | Language::Go | Language::Golang => {
+            stem.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        },
+    };
+    if stem.is_empty() || !valid_stem {
+        bail!("Invalid --entrypoint '{}': not a valid .{} file name", name, extension);
+    }
+    if with_multi_file && matches!(stem, "weather" | "tools" | "lib") {
+        bail!("Invalid --entrypoint '{}': it clashes with a module generated by --with-multi-file", name);
+    }
+    
+    Ok(file_name)
+}
+
+/// File name of the generated MCP Inspector config
+const INSPECTOR_CONFIG: &str = "mcp-inspector.json";
+
+/// Writes an MCP Inspector config launching the server with `command` from the project directory
+pub(crate) fn write_inspector_config(files: &ProjectFiles, server_name: &str, command: &[String]) -> Result<()> {
+    let config = serde_json::json!({
+        "mcpServers": {
+            server_name: {
+                "command": command[0],
+                "args": command[1..],
+            }
+        }
+    });
+    
+    files.write(INSPECTOR_CONFIG, serde_json::to_string_pretty(&config)? + "\n")
+}
+
+/// Sets the repository-local git identity requested with `--git-user` / `--git-email`
+pub(crate) fn configure_git_identity(files: &ProjectFiles, project_path: &Path, options: &GeneratorOptions) -> Result<()> {
+    let identity = [("user.name", &options.git_user), ("user.email", &options.git_email)];
+    for (key, value) in identity {
+        let Some(value) = value else {
+            continue;
+        };
+        let args = ["config".to_string(), key.to_string(), value.clone()];
+        if files.is_dry_run() {
+            files.record_command(&[&["git".to_string()], &args[..]].concat());
+            continue;
+        }
+        
+        let status = Command::new("git")
+            .args(&args)
+            .current_dir(project_path)
+            .status()
+            .with_context(|| format!("Failed to set git {}", key))?;
+        if !status.success() {
+            bail!("Failed to set git {}", key);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Writes the workflow for the selected CI service, running `steps` after the checkout
+pub(crate) fn write_ci_workflow(files: &ProjectFiles, options: &GeneratorOptions, steps: &str) -> Result<()> {
+    let Some(Ci::Github) = options.ci else {
+        return Ok(());
+    };
+    
+    files.create_dir(".github/workflows")?;
+    files.write(".github/workflows/ci.yml", format!(r#"name: CI
+
+on:
+  push:
+    branches: [main]
+  pull_request:
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+{}"#, steps))
+}
+
+/// Writes the LICENSE file for the selected license, if any
+pub(crate) fn write_license(files: &ProjectFiles, options: &GeneratorOptions, project_name: &str) -> Result<()> {
+    let holder = options.author.clone().unwrap_or_else(|| format!("The {} Authors", project_name));
+    match license_text(&options.license, &holder) {
+        Some(text) => files.write("LICENSE", text),
+        None => Ok(()),
+    }
+}
+
+/// README section explaining how to open the generated Inspector config, if any
+pub(crate) fn inspector_readme_section(options: &GeneratorOptions) -> String {
+    if !options.with_inspector_config {
+        return String::new();
+    }
+    
+    format!(r#"### Debugging with the MCP Inspector
+
+`{}` configures the [MCP Inspector](https://github.com/modelcontextprotocol/inspector) to launch this server. From the project directory run:
+
+```bash
+npx @modelcontextprotocol/inspector --config {} --server {}
+```
+
+"#, INSPECTOR_CONFIG, INSPECTOR_CONFIG, shell_words::quote(options.server_name()))
+}
+
+/// README section showing how to reach a Streamable HTTP server with curl, if selected
+pub(crate) fn http_readme_section(options: &GeneratorOptions) -> String {
+    if !matches!(options.transport, Transport::Http) {
+        return String::new();
+    }
+    
+    format!(r#"### Calling the Server over HTTP
+
+The server speaks the Streamable HTTP transport on `http://127.0.0.1:{port}/mcp` (set `PORT` to change the port). Start a session with an `initialize` request:
+
+```bash
+curl -i http://127.0.0.1:{port}/mcp \
+  -H "Content-Type: application/json" \
+  -H "Accept: application/json, text/event-stream" \
+  -d '{{"jsonrpc":"2.0","id":1,"method":"initialize","params":{{"protocolVersion":"2025-03-26","capabilities":{{}},"clientInfo":{{"name":"curl","version":"1.0.0"}}}}}}'
+```
+
+Then pass the `mcp-session-id` response header along with further requests, e.g. to list the tools:
+
+```bash
+curl http://127.0.0.1:{port}/mcp \
+  -H "Content-Type: application/json" \
+  -H "Accept: application/json, text/event-stream" \
+  -H "Mcp-Session-Id: <session id>" \
+  -d '{{"jsonrpc":"2.0","id":2,"method":"tools/list"}}'
+```
+
+"#, port = options.port())
+}
+
+/// Removes the line comments from generated source code, keeping comment markers inside
+/// strings as well as shebangs and tool directives (`# type:`, `# noqa`, `// @ts-`)
+pub(crate) fn strip_comments(code: &str, language: &Language) -> String {
+    let python = matches!(language, Language::Python | Language::Py);
+    let (marker, delimiters): (&str, &[&str]) = if python {
+        ("#", &["\"\"\"", "'''", "\"", "'"])
+    } else {
+        ("//", &["`", "\"", "'"])
+    };
+    let directives: &[&str] = if python { &["# type:", "# noqa"] } else { &["// @ts-", "// eslint-"] };
+    
+    let mut lines: Vec<&str> = Vec::new();
+    let mut string: Option<&str> = None;
+    let mut dropped = false;
+    for (index, line) in code.lines().enumerate() {
+        let mut comment = None;
+        let mut chars = line.char_indices();
+        while let Some((i, c)) = chars.next() {
+            let rest = &line[i..];
+            match string {
+                Some(_) if c == '\\' => {
+                    chars.next();
+                },
+                Some(delimiter) if rest.starts_with(delimiter) => {
+                    // Delimiters are ASCII, so skip the rest of a multi-character one by bytes
+                    chars.by_ref().take(delimiter.len() - 1).for_each(drop);
+                    string = None;
+                },
+                Some(_) => {},
+                None if rest.starts_with(marker) => {
+                    comment = Some(i);
+                    break;
+                },
+                None => {
+                    if let Some(delimiter) = delimiters.iter().find(|delimiter| rest.starts_with(*delimiter)) {
+                        chars.by_ref().take(delimiter.len() - 1).for_each(drop);
+                        string = Some(delimiter);
+                    }
+                },
+            }
+        }
+        // Only triple-quoted strings and template literals continue on the next line
+        if matches!(string, Some("\"") | Some("'")) {
+            string = None;
+        }
+        
+        let line = match comment {
+            Some(start) => {
+                let text = &line[start..];
+                let keep = (index == 0 && text.starts_with("#!"))
+                    || directives.iter().any(|directive| text.starts_with(directive));
+                if keep {
+                    line
+                } else if line[..start].trim().is_empty() {
+                    dropped = true;
+                    continue;
+                } else {
+                    line[..start].trim_end()
+                }
+            },
+            None => line,
+        };
+        // Don't leave a doubled blank line where a comment stood between two blank lines
+        if line.trim().is_empty() && dropped && lines.last().is_none_or(|last| last.trim().is_empty()) {
+            continue;
+        }
+        dropped = false;
+        lines.push(line);
+    }
+    
+    let mut stripped = lines.join("\n");
+    if code.ends_with('\n') {
+        stripped.push('\n');
+    }
+    stripped
+}
+
+/// Prints the dependencies written to `manifest` as an aligned name/version/kind table
+pub(crate) fn print_dependency_table(manifest: &str, dependencies: &[(&str, &str, &str)]) {
+    let name_width = dependencies.iter().map(|(name, _, _)| name.len()).max().unwrap_or(0).max("PACKAGE".len());
+    let version_width = dependencies.iter().map(|(_, version, _)| version.len()).max().unwrap_or(0).max("VERSION".len());
+    
+    println!("\n📦 Dependencies in {}:", manifest);
+    println!("  {:<name_width$}  {:<version_width$}  TYPE", "PACKAGE", "VERSION");
+    for (name, version, kind) in dependencies {
+        println!("  {:<name_width$}  {:<version_width$}  {}", name, version, kind);
+    }
+}
+
+/// Writes a JSON report of a finished generation: arguments, tool versions, timestamps and file hashes
+pub(crate) fn write_summary(path: &Path, language: &str, programs: &[&str], files: &ProjectFiles) -> Result<()> {
+    let versions: serde_json::Map<String, serde_json::Value> = programs.iter()
+        .map(|program| (program.to_string(), detect_version(program).into()))
+        .collect();
+    let written_files: Vec<serde_json::Value> = files.written_files().into_iter()
+        .map(|(path, sha256)| serde_json::json!({ "path": path, "sha256": sha256 }))
+        .collect();
+    
+    let summary = serde_json::json!({
+        "mcpc_version": env!("CARGO_PKG_VERSION"),
+        "arguments": std::env::args().skip(1).collect::<Vec<_>>(),
+        "project": files.root().display().to_string(),
+        "language": language,
+        "versions": versions,
+        "started_at": timestamp(files.started_at()),
+        "finished_at": timestamp(SystemTime::now()),
+        "files": written_files,
+    });
+    
+    fs::write(path, serde_json::to_string_pretty(&summary)? + "\n")
+        .context(format!("Failed to write summary to {}", path.display()))
+}
+
+/// RFC 3339 timestamp in UTC with second precision
+fn timestamp(time: SystemTime) -> String {
+    humantime::format_rfc3339_seconds(time).to_string()
+}
+
+/// Prints the commands about to run and asks the user to confirm them
+pub(crate) fn confirm_commands(commands: &[&Vec<String>]) -> Result<bool> {
+    println!("The following commands will be run:");
+    for command in commands {
+        println!("  $ {}", shell_words::join(command.iter()));
+    }
+    print!("Proceed? [y/N] ");
+    io::stdout().flush()?;
+    
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).context("Failed to read confirmation")?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Removes a project directory created by a generation that failed midway, so a rerun
+/// is not blocked by the half-written directory; `disarm` it once generation succeeds
+pub struct ProjectDirGuard {
+    path: Option<PathBuf>,
+}
+
+impl ProjectDirGuard {
+    /// Guards `path` unless it already exists (or nothing will be written because of a dry run)
+    pub fn new(path: &Path, dry_run: bool) -> Self {
+        let path = (!dry_run && !path.exists()).then(|| path.to_path_buf());
+        Self { path }
+    }
+    
+    /// Keeps the directory
+    pub fn disarm(mut self) {
+        self.path = None;
+    }
+}
+
+impl Drop for ProjectDirGuard {
+    fn drop(&mut self) {
+        let Some(path) = self.path.take() else {
+            return;
+        };
+        if !path.exists() {
+            return;
+        }
+        
+        match fs::remove_dir_all(&path) {
+            Ok(()) => eprintln!("Removed the partially created project directory: {}", path.display()),
+            Err(e) => eprintln!("Failed to remove the partially created project directory {}: {}", path.display(), e),
+        }
+    }
+}
 
 /// Trait for project generators
 pub trait Generator {
     /// Creates a new generator for the specified project
-    fn new(project_name: &str, tool: &Tool) -> Self;
+    fn new(project_name: &str, tool: &Tool, options: &GeneratorOptions) -> Self;
     
     /// Generates the project scaffold
     fn generate(&self) -> Result<()>;
@@ -0,0 +1,225 @@
+use crate::LineEndings;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::borrow::Cow;
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Writes the files of a generated project, or only records them when previewing with --dry-run
+#[derive(Debug)]
+pub struct ProjectFiles {
+    root: PathBuf,
+    dry_run: bool,
+    /// Write text files with CRLF instead of LF newlines
+    crlf: bool,
+    /// Directories (with a trailing `/`) and files that would be created, relative to the root
+    planned_paths: RefCell<BTreeSet<String>>,
+    /// Commands that would run inside the project, in order
+    planned_commands: RefCell<Vec<String>>,
+    /// SHA-256 of every file written, keyed by its path relative to the root
+    written: RefCell<BTreeMap<String, String>>,
+    started_at: SystemTime,
+}
+
+impl ProjectFiles {
+    pub fn new(root: PathBuf, dry_run: bool, line_endings: &LineEndings) -> Self {
+        let crlf = match line_endings {
+            LineEndings::Lf => false,
+            LineEndings::Crlf => true,
+            LineEndings::Auto => cfg!(windows),
+        };
+
+        Self {
+            root,
+            dry_run,
+            crlf,
+            planned_paths: RefCell::new(BTreeSet::new()),
+            planned_commands: RefCell::new(Vec::new()),
+            written: RefCell::new(BTreeMap::new()),
+            started_at: SystemTime::now(),
+        }
+    }
+
+    /// Whether side effects are only recorded
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Creates the project root, failing if it already exists unless `reuse_existing` is set
+    pub fn create_root(&self, reuse_existing: bool) -> Result<()> {
+        if self.dry_run {
+            self.planned_paths.borrow_mut().insert(String::new());
+            return Ok(());
+        }
+        if reuse_existing && self.root.is_dir() {
+            return Ok(());
+        }
+        // The project directory itself must be new, but e.g. `--path servers/weather` may need `servers/`
+        if let Some(parent) = self.root.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .context(format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        fs::create_dir(&self.root)
+            .context(format!("Failed to create project directory: {}", self.root.display()))
+    }
+
+    /// Creates a directory (and its parents) inside the project
+    pub fn create_dir(&self, relative: &str) -> Result<()> {
+        if self.dry_run {
+            self.planned_paths.borrow_mut().insert(format!("{}/", relative));
+            return Ok(());
+        }
+
+        fs::create_dir_all(self.root.join(relative))
+            .context(format!("Failed to create directory: {}", relative))
+    }
+
+    /// Writes a file inside the project, normalizing the newlines of text content
+    pub fn write(&self, relative: &str, contents: impl AsRef<[u8]>) -> Result<()> {
+        if self.dry_run {
+            self.planned_paths.borrow_mut().insert(relative.to_string());
+            return Ok(());
+        }
+
+        let contents = normalize_line_endings(contents.as_ref(), self.crlf);
+        fs::write(self.root.join(relative), &contents)
+            .context(format!("Failed to create {}", relative))?;
+        self.written.borrow_mut().insert(relative.to_string(), sha256_hex(contents.as_ref()));
+        Ok(())
+    }
+
+    /// Copies an outside file into the project
+    pub fn copy(&self, from: &Path, relative: &str) -> Result<()> {
+        if self.dry_run {
+            self.planned_paths.borrow_mut().insert(relative.to_string());
+            return Ok(());
+        }
+
+        let contents = fs::read(from)
+            .context(format!("Failed to copy {} from {}", relative, from.display()))?;
+        self.write(relative, contents)
+    }
+
+    /// Marks a file inside the project as executable (no-op outside Unix)
+    pub fn set_executable(&self, relative: &str) -> Result<()> {
+        #[cfg(unix)]
+        if !self.dry_run {
+            use std::os::unix::fs::PermissionsExt;
+            let path = self.root.join(relative);
+            let mut perms = fs::metadata(&path)?.permissions();
+            perms.set_mode(0o755);  // rwxr-xr-x
+            fs::set_permissions(&path, perms)
+                .context(format!("Failed to make {} executable", relative))?;
+        }
+
+        Ok(())
+    }
+
+    /// Project root the files are written into
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// When the generator started writing the project
+    pub fn started_at(&self) -> SystemTime {
+        self.started_at
+    }
+
+    /// Paths and SHA-256 hashes of the files written so far, sorted by path
+    pub fn written_files(&self) -> Vec<(String, String)> {
+        self.written.borrow().iter()
+            .map(|(path, hash)| (path.clone(), hash.clone()))
+            .collect()
+    }
+
+    /// Records a command that would run in the project during a dry run
+    pub fn record_command(&self, command: &[String]) {
+        self.planned_commands.borrow_mut().push(shell_words::join(command));
+    }
+
+    /// Prints the recorded paths (sorted) and commands (in run order)
+    pub fn print_plan(&self) {
+        println!("🔍 Dry run: the following would be created");
+        for path in self.planned_paths.borrow().iter() {
+            println!("  {}", self.root.join(path).display());
+        }
+
+        let commands = self.planned_commands.borrow();
+        if !commands.is_empty() {
+            println!("🔍 and these commands would run in {}:", self.root.display());
+            for command in commands.iter() {
+                println!("  $ {}", command);
+            }
+        }
+    }
+
+    /// Prints the files written (or planned, in a dry run) as a tree like the `tree` command
+    pub fn print_tree(&self) {
+        // Planned directories end with `/`, so empty ones still show up as directories
+        let paths: Vec<String> = if self.dry_run {
+            self.planned_paths.borrow().iter().filter(|path| !path.is_empty()).cloned().collect()
+        } else {
+            self.written.borrow().keys().cloned().collect()
+        };
+
+        let mut tree = TreeNode::default();
+        for path in &paths {
+            let node = path.trim_end_matches('/').split('/')
+                .fold(&mut tree, |node, name| node.children.entry(name.to_string()).or_default());
+            node.is_dir |= path.ends_with('/');
+        }
+
+        let root = self.root.file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.root.display().to_string());
+        println!("\n📂 {}", format!("{}/", root).blue().bold());
+        tree.print("");
+    }
+}
+
+/// Directory entries of the printed project tree, sorted by name
+#[derive(Default)]
+struct TreeNode {
+    children: BTreeMap<String, TreeNode>,
+    is_dir: bool,
+}
+
+impl TreeNode {
+    /// Prints the entries one per line, with `prefix` carrying the branch lines of the parents
+    fn print(&self, prefix: &str) {
+        let count = self.children.len();
+        for (i, (name, child)) in self.children.iter().enumerate() {
+            let last = i + 1 == count;
+            let branch = if last { "└── " } else { "├── " };
+            if child.children.is_empty() && !child.is_dir {
+                println!("{}{}{}", prefix, branch, name);
+            } else {
+                println!("{}{}{}", prefix, branch, format!("{}/", name).blue().bold());
+                child.print(&format!("{}{}", prefix, if last { "    " } else { "│   " }));
+            }
+        }
+    }
+}
+
+/// Rewrites every newline as LF or CRLF, leaving content that is not UTF-8 untouched
+fn normalize_line_endings(contents: &[u8], crlf: bool) -> Cow<'_, [u8]> {
+    let Ok(text) = std::str::from_utf8(contents) else {
+        return Cow::Borrowed(contents);
+    };
+
+    let lf = text.replace("\r\n", "\n");
+    let normalized = if crlf { lf.replace('\n', "\r\n") } else { lf };
+    Cow::Owned(normalized.into_bytes())
+}
+
+/// Lowercase hex SHA-256 digest
+fn sha256_hex(contents: &[u8]) -> String {
+    Sha256::digest(contents).iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -1,26 +1,45 @@
-use anyhow::{Context, Result};
-use std::fs;
+use anyhow::{bail, Context, Result};
 use std::path::PathBuf;
 use std::process::Command;
 use colored::*;
 
-use crate::Tool;
-use super::Generator;
+use crate::{Language, Template, Tool, Transport};
+use crate::utils::openapi::{tool_name, OpenApiTools, ParamKind};
+use crate::utils::dependency_checker::MIN_PYTHON_VERSION;
+use crate::utils::license;
+use super::docker::{docker_readme_section, write_dockerfile};
+use super::{configure_git_identity, confirm_commands, http_readme_section, inspector_readme_section, print_dependency_table, project_files::ProjectFiles, strip_comments, write_ci_workflow, write_inspector_config, write_license, write_summary, Generator, GeneratorOptions, DEFAULT_HTTP_PORT, DEFAULT_PORT, DEFAULT_WEATHER_API};
+
+/// Default entrypoint file name
+pub const DEFAULT_ENTRYPOINT: &str = "server.py";
+
+/// Interpreter used to create virtual environments with the `venv` module
+pub const SYSTEM_PYTHON: &str = if cfg!(windows) { "python" } else { "python3" };
+
+/// Python inside the project's virtual environment
+pub const VENV_PYTHON: &str = if cfg!(windows) { ".venv\\Scripts\\python.exe" } else { ".venv/bin/python" };
+
+/// pip inside the project's virtual environment
+const VENV_PIP: &str = if cfg!(windows) { ".venv\\Scripts\\pip.exe" } else { ".venv/bin/pip" };
 
 pub struct PythonGenerator {
     project_name: String,
-    _tool: Tool,
+    tool: Tool,
     project_path: PathBuf,
+    files: ProjectFiles,
+    options: GeneratorOptions,
 }
 
 impl Generator for PythonGenerator {
-    fn new(project_name: &str, tool: &Tool) -> Self {
-        let project_path = PathBuf::from(project_name);
+    fn new(project_name: &str, tool: &Tool, options: &GeneratorOptions) -> Self {
+        let project_path = options.project_path(project_name);
         
         Self {
             project_name: project_name.to_string(),
-            _tool: tool.clone(),
+            tool: tool.clone(),
+            files: ProjectFiles::new(project_path.clone(), options.dry_run, &options.line_endings),
             project_path,
+            options: options.clone(),
         }
     }
     
@@ -32,20 +51,58 @@ impl Generator for PythonGenerator {
         self.create_files()?;
         
         // Initialize package manager
-        self.init_package_manager()?;
+        if !self.options.no_install {
+            self.init_package_manager()?;
+        }
         
         // Initialize git
-        self.init_git()?;
+        if !self.options.no_git {
+            self.init_git()?;
+        }
+        
+        if self.files.is_dry_run() {
+            self.files.print_plan();
+        }
+        
+        if self.options.print_tree_after {
+            self.files.print_tree();
+        }
+        
+        if self.options.dependency_table {
+            let dependencies: Vec<_> = self.dependencies()
+                .into_iter()
+                .map(|requirement| {
+                    let (name, constraint) = split_requirement(requirement);
+                    (name, constraint, "runtime")
+                })
+                .chain(self.dev_dependencies().into_iter().map(|requirement| {
+                    let (name, constraint) = split_requirement(requirement);
+                    (name, constraint, "dev")
+                }))
+                .collect();
+            print_dependency_table("pyproject.toml", &dependencies);
+        }
+        
+        if let Some(path) = &self.options.summary_json {
+            let package_manager = format!("{:?}", self.tool).to_lowercase();
+            write_summary(path, "python", &["python3", &package_manager], &self.files)?;
+        }
         
         Ok(())
     }
     
     fn create_directories(&self) -> Result<()> {
         // Create main directory
-        fs::create_dir(&self.project_path)
-            .context(format!("Failed to create project directory: {}", self.project_path.display()))?;
+        self.files.create_root(self.options.allow_existing_empty)?;
+        
+        // Multi-file projects keep their tools in a `weather` package next to server.py
+        if self.options.with_multi_file {
+            self.files.create_dir("weather")?;
+        }
         
-        // No need for subdirectories with the new structure - all code is in the main server.py file
+        if self.options.with_tests {
+            self.files.create_dir("tests")?;
+        }
         
         Ok(())
     }
@@ -54,48 +111,141 @@ impl Generator for PythonGenerator {
         // Create pyproject.toml
         self.create_pyproject_toml()?;
         
-        // Create requirements.txt
-        self.create_requirements_txt()?;
+        if self.is_poetry() {
+            // Keep Poetry's virtual environment in .venv like the uv setup
+            self.files.write("poetry.toml", "[virtualenvs]\nin-project = true\n")?;
+        } else {
+            // Create requirements.txt
+            self.create_requirements_txt()?;
+        }
         
         // Create .gitignore
         self.create_gitignore()?;
         
+        // Create LICENSE
+        write_license(&self.files, &self.options, &self.project_name)?;
+        
+        // Create .dockerignore
+        if self.options.with_dockerignore_only || self.options.docker {
+            self.create_dockerignore()?;
+        }
+        
+        // Create Dockerfile
+        if self.options.docker {
+            self.create_dockerfile()?;
+        }
+        
+        // Create the CI workflow
+        if self.options.ci.is_some() {
+            self.create_ci()?;
+        }
+        
         // Create main server file
         self.create_server_file()?;
         
+        // Create the pytest suite
+        if self.options.with_tests {
+            self.create_tests()?;
+        }
+        
+        // Create ruff.toml
+        if self.options.with_lint {
+            self.create_ruff_config()?;
+        }
+        
+        // Create mcp-inspector.json
+        if self.options.with_inspector_config {
+            write_inspector_config(&self.files, self.options.server_name(), &self.launch_command())?;
+        }
+        
         // Create README
         self.create_readme()?;
         
+        // Create QUICKSTART.md
+        if self.options.readme_quickstart_only {
+            self.create_quickstart()?;
+        }
+        
         Ok(())
     }
     
     fn init_package_manager(&self) -> Result<()> {
-        // Create virtual environment using uv
-        println!("📦 Creating Python virtual environment with uv...");
+        if self.options.frozen_lockfile {
+            return self.install_from_lockfile();
+        }
+        
+        if self.is_poetry() {
+            return self.install_with_poetry();
+        }
+        
+        let venv_command = self.venv_command();
+        let install_command = self.install_command();
+        let tool_name = if self.is_pip() { "pip" } else { "uv" };
+        
+        if self.files.is_dry_run() {
+            self.files.record_command(&venv_command);
+            self.files.record_command(&install_command);
+            return Ok(());
+        }
         
-        // Use uv to create the virtual environment
-        let venv_result = Command::new("uv")
-            .args(["venv"])
+        if self.options.confirm_install_commands && !confirm_commands(&[&venv_command, &install_command])? {
+            println!("⏭️  Skipping virtual environment and dependency installation");
+            return Ok(());
+        }
+        
+        // Create virtual environment using uv (or the venv module for pip)
+        println!("📦 Creating Python virtual environment with {}...", if self.is_pip() { "venv" } else { "uv" });
+        
+        let venv_result = Command::new(&venv_command[0])
+            .args(&venv_command[1..])
             .current_dir(&self.project_path)
             .output()
-            .context("Failed to create virtual environment with uv venv")?;
+            .context(format!("Failed to create virtual environment with {}", shell_words::join(&venv_command)))?;
         
-        if !venv_result.status.success() {
+        let installed = if !venv_result.status.success() {
             let error = String::from_utf8_lossy(&venv_result.stderr);
             eprintln!("⚠️ Warning: Failed to create virtual environment: {}", error);
-            eprintln!("Please run 'uv venv' manually in the project directory");
+            eprintln!("Please run '{}' manually in the project directory", shell_words::join(&venv_command));
+            false
         } else {
             println!("✅ Virtual environment created successfully");
+            
+            // Install dependencies into the new virtual environment
+            println!("📦 Installing dependencies with {}...", tool_name);
+            let install_result = Command::new(self.program(&install_command[0]))
+                .args(&install_command[1..])
+                .current_dir(&self.project_path)
+                .output()
+                .context(format!("Failed to install dependencies with {}", shell_words::join(&install_command)))?;
+            
+            if !install_result.status.success() {
+                let error = String::from_utf8_lossy(&install_result.stderr);
+                eprintln!("⚠️ Warning: Failed to install dependencies: {}", error);
+                eprintln!("Please run '{}' manually in the project directory", shell_words::join(&install_command));
+                false
+            } else {
+                println!("✅ Dependencies installed successfully");
+                true
+            }
+        };
+
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
         }
-
+        
         println!("\n{} 📦 Python virtual environment created!", "Success:".green().bold());
         println!("\n{}", "Next steps:".blue().bold());
         println!("1. Activate the virtual environment:");
         println!("   {}  source .venv/bin/activate  {}", "$".bold(), "# On Windows: .venv\\Scripts\\activate".dimmed());
-        println!("2. Install dependencies:");
-        println!("   {}  uv pip install -r requirements.txt", "$".bold());
-        println!("3. Run the server in test mode to verify it's working:");
-        println!("   {}  python server.py --test", "$".bold());
+        // Installing is only left to the user when it failed above
+        let mut step = 2;
+        if !installed {
+            println!("{}. Install dependencies:", step);
+            println!("   {}  {} install -r requirements.txt", "$".bold(), if self.is_pip() { "pip" } else { "uv pip" });
+            step += 1;
+        }
+        println!("{}. Run the server in test mode to verify it's working:", step);
+        println!("   {}  python {} --test", "$".bold(), self.entrypoint());
         println!("\n{}", "Note:".yellow().bold());
         println!("If you run the server without --test, it will appear to hang. This is normal!");
         println!("The server is waiting for MCP protocol messages on stdin and is designed to be");
@@ -106,18 +256,222 @@ impl Generator for PythonGenerator {
     }
     
     fn init_git(&self) -> Result<()> {
+        if self.files.is_dry_run() {
+            self.files.record_command(&["git".to_string(), "init".to_string()]);
+            return configure_git_identity(&self.files, &self.project_path, &self.options);
+        }
+        
         Command::new("git")
             .args(["init"])
             .current_dir(&self.project_path)
             .output()
             .context("Failed to initialize git repository")?;
         
-        Ok(())
+        configure_git_identity(&self.files, &self.project_path, &self.options)
     }
 }
 
 impl PythonGenerator {
+    /// Whether the server starts from the minimal echo template instead of the weather example
+    fn is_minimal(&self) -> bool {
+        matches!(self.options.template, Template::Minimal)
+    }
+    
+    /// Whether dependencies are managed by Poetry instead of uv
+    fn is_poetry(&self) -> bool {
+        matches!(self.tool, Tool::Poetry)
+    }
+    
+    /// Whether the virtual environment is created with the `venv` module and filled by pip
+    fn is_pip(&self) -> bool {
+        matches!(self.tool, Tool::Pip)
+    }
+    
+    /// Program to spawn, resolving virtual environment executables to absolute paths in the project
+    fn program(&self, program: &str) -> PathBuf {
+        if program.starts_with(".venv") {
+            let path = self.project_path.join(program);
+            std::path::absolute(&path).unwrap_or(path)
+        } else {
+            PathBuf::from(program)
+        }
+    }
+    
+    /// Prefix for running Python inside the project's environment
+    fn python_command(&self) -> &'static str {
+        if self.is_poetry() {
+            "poetry run python"
+        } else {
+            "python"
+        }
+    }
+    
+    /// Command installing the dependencies declared in pyproject.toml with Poetry
+    fn poetry_install_command(&self) -> Vec<String> {
+        let mut command = vec!["poetry".to_string(), "install".to_string()];
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Creates the virtual environment and installs the dependencies with `poetry install`
+    fn install_with_poetry(&self) -> Result<()> {
+        let install_command = self.poetry_install_command();
+        
+        if self.files.is_dry_run() {
+            self.files.record_command(&install_command);
+            return Ok(());
+        }
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&install_command])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
+        println!("📦 Installing dependencies with poetry...");
+        let output = Command::new(&install_command[0])
+            .args(&install_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to install dependencies with poetry install")?;
+        
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            eprintln!("⚠️ Warning: Failed to install dependencies: {}", error);
+            eprintln!("Please run 'poetry install' manually in the project directory");
+        } else {
+            println!("✅ Dependencies installed successfully");
+        }
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        println!("\n{}", "Next steps:".blue().bold());
+        println!("1. Run the server in test mode to verify it's working:");
+        println!("   {}  poetry run python {} --test", "$".bold(), self.entrypoint());
+        println!("\nSee the README.md for more information on how to set up with Claude for Desktop.");
+        
+        Ok(())
+    }
+    
+    /// Command starting the server from the project directory
+    fn launch_command(&self) -> Vec<String> {
+        let runner: &[&str] = if self.is_poetry() {
+            &["poetry", "run", "python"]
+        } else if self.is_pip() {
+            &[VENV_PYTHON]
+        } else {
+            &["uv", "run"]
+        };
+        let mut command: Vec<String> = runner.iter().map(|arg| arg.to_string()).collect();
+        command.push(self.entrypoint().to_string());
+        command
+    }
+    
+    /// Entrypoint file name
+    fn entrypoint(&self) -> &str {
+        self.options.entrypoint_or(DEFAULT_ENTRYPOINT)
+    }
+    
+    /// Runtime dependencies declared in pyproject.toml and requirements.txt
+    fn dependencies(&self) -> Vec<&'static str> {
+        // FastMCP gained the streamable-http transport in 1.8 and completion handlers in 1.10
+        let mcp = if self.options.with_completion_example {
+            "mcp[cli]>=1.10.0"
+        } else if matches!(self.options.transport, Transport::Http) {
+            "mcp[cli]>=1.8.0"
+        } else {
+            "mcp[cli]>=1.2.0"
+        };
+        let mut dependencies = vec![mcp];
+        // Only the weather example and the OpenAPI tools make HTTP requests
+        let makes_requests = !self.is_minimal() || self.options.openapi_tools.is_some();
+        if makes_requests && !self.options.minimal_deps {
+            dependencies.push("httpx>=0.24.0");
+        }
+        if matches!(self.options.transport, Transport::Ws) {
+            dependencies.push("websockets>=15.0");
+        }
+        if self.options.with_otel {
+            dependencies.extend(["opentelemetry-sdk>=1.29.0", "opentelemetry-exporter-otlp-proto-http>=1.29.0"]);
+        }
+        dependencies
+    }
+    
+    /// Test and lint dependencies, kept out of the runtime dependencies in pyproject.toml
+    fn dev_dependencies(&self) -> Vec<&'static str> {
+        let mut dependencies = Vec::new();
+        if self.options.with_tests {
+            dependencies.extend(["pytest>=8.0", "pytest-asyncio>=0.24"]);
+        }
+        if self.options.with_lint {
+            dependencies.push("ruff>=0.6.0");
+        }
+        dependencies
+    }
+    
+    /// pytest settings, running the async tests without markers and importing the server from the project root
+    fn pytest_config(&self) -> &'static str {
+        if self.options.with_tests {
+            "
+[tool.pytest.ini_options]
+asyncio_mode = \"auto\"
+pythonpath = [\".\"]
+testpaths = [\"tests\"]
+"
+        } else {
+            ""
+        }
+    }
+    
     fn create_pyproject_toml(&self) -> Result<()> {
+        if self.is_poetry() {
+            return self.create_poetry_pyproject_toml();
+        }
+        
+        let dependencies: String = self.dependencies()
+            .iter()
+            .map(|dep| format!("    \"{}\",\n", dep))
+            .collect();
+        let module = self.entrypoint().trim_end_matches(".py");
+        // The console script imports the entrypoint, so it has to be installed as a module
+        let py_modules = if self.options.python_entry_console_script {
+            format!("py-modules = [\"{}\"]", module)
+        } else {
+            "py-modules = []".to_string()
+        };
+        let setuptools_config = if self.options.with_multi_file {
+            format!("{}\npackages = [\"weather\"]", py_modules)
+        } else {
+            py_modules
+        };
+        let scripts = if self.options.python_entry_console_script {
+            format!("\n[project.scripts]\n{} = \"{}:main\"\n", self.project_name, module)
+        } else {
+            String::new()
+        };
+        let dev_dependencies: String = self.dev_dependencies()
+            .iter()
+            .map(|dep| format!("    \"{}\",\n", dep))
+            .collect();
+        let extras = if dev_dependencies.is_empty() {
+            String::new()
+        } else {
+            format!("\n[project.optional-dependencies]\ndev = [\n{}]\n", dev_dependencies)
+        };
+        let author_fields: Vec<_> = [("name", &self.options.author), ("email", &self.options.email)]
+            .into_iter()
+            .filter_map(|(key, value)| value.as_deref().map(|value| format!("{} = {}", key, py_string(value))))
+            .collect();
+        let authors = if author_fields.is_empty() {
+            String::new()
+        } else {
+            format!("authors = [\n    {{{}}},\n]\n", author_fields.join(", "))
+        };
+        let classifier = license::python_classifier(&self.options.license)
+            .map(|classifier| format!("    \"{}\",\n", classifier))
+            .unwrap_or_default();
+        
         let pyproject_toml = format!(r#"[build-system]
 requires = ["setuptools>=61.0"]
 build-backend = "setuptools.build_meta"
@@ -126,42 +480,97 @@ build-backend = "setuptools.build_meta"
 name = "{}"
 version = "0.1.0"
 description = "MCP (Model Context Protocol) Weather Server"
-authors = [
-    {{name = "Your Name", email = "your.email@example.com"}},
-]
-readme = "README.md"
+{}readme = "README.md"
 requires-python = ">=3.10"
 classifiers = [
     "Programming Language :: Python :: 3",
-    "License :: OSI Approved :: MIT License",
-    "Operating System :: OS Independent",
+{}    "Operating System :: OS Independent",
 ]
 dependencies = [
-    "mcp[cli]>=1.2.0",
-    "httpx>=0.24.0",
-]
-
+{}]
+{}{}
 [tool.setuptools]
-py-modules = []
-"#, self.project_name);
+{}
+{}"#, self.project_name, authors, classifier, dependencies, extras, scripts, setuptools_config, self.pytest_config());
+        
+        self.files.write("pyproject.toml", pyproject_toml)?;
+        
+        Ok(())
+    }
+    
+    /// pyproject.toml declaring the project and its dependencies in Poetry's `[tool.poetry]` tables
+    fn create_poetry_pyproject_toml(&self) -> Result<()> {
+        let dependencies: String = self.dependencies()
+            .iter()
+            .map(|dep| format!("{}\n", poetry_dependency(dep)))
+            .collect();
+        let module = self.entrypoint().trim_end_matches(".py");
+        // Only a console script needs the project itself installed
+        let packaging = if self.options.python_entry_console_script {
+            let mut packages = format!("{{ include = \"{}\" }}", self.entrypoint());
+            if self.options.with_multi_file {
+                packages.push_str(", { include = \"weather\" }");
+            }
+            format!("packages = [{}]", packages)
+        } else {
+            "package-mode = false".to_string()
+        };
+        let scripts = if self.options.python_entry_console_script {
+            format!("\n[tool.poetry.scripts]\n{} = \"{}:main\"\n", self.project_name, module)
+        } else {
+            String::new()
+        };
+        // `poetry install` also installs the dev group
+        let dev_dependencies: String = self.dev_dependencies()
+            .iter()
+            .map(|dep| format!("{}\n", poetry_dependency(dep)))
+            .collect();
+        let dev_group = if dev_dependencies.is_empty() {
+            String::new()
+        } else {
+            format!("\n[tool.poetry.group.dev.dependencies]\n{}", dev_dependencies)
+        };
+        let authors = self.options.author()
+            .map(|author| format!("authors = [{}]\n", py_string(&author)))
+            .unwrap_or_default();
+        let license = license::spdx_id(&self.options.license)
+            .map(|id| format!("license = \"{}\"\n", id))
+            .unwrap_or_default();
+        
+        let pyproject_toml = format!(r#"[tool.poetry]
+name = "{}"
+version = "0.1.0"
+description = "MCP (Model Context Protocol) Weather Server"
+{}{}readme = "README.md"
+{}
+
+[tool.poetry.dependencies]
+python = "^3.10"
+{}{}{}
+[build-system]
+requires = ["poetry-core>=1.0.0"]
+build-backend = "poetry.core.masonry.api"
+{}"#, self.project_name, authors, license, packaging, dependencies, dev_group, scripts, self.pytest_config());
         
-        fs::write(
-            self.project_path.join("pyproject.toml"),
-            pyproject_toml,
-        ).context("Failed to create pyproject.toml")?;
+        self.files.write("pyproject.toml", pyproject_toml)?;
         
         Ok(())
     }
     
     fn create_requirements_txt(&self) -> Result<()> {
-        let requirements = r#"mcp[cli]>=1.2.0
-httpx>=0.24.0
-"#;
+        let mut requirements: String = self.dependencies()
+            .iter()
+            .map(|dep| format!("{}\n", dep))
+            .collect();
+        let dev_dependencies = self.dev_dependencies();
+        if !dev_dependencies.is_empty() {
+            requirements.push_str("\n# Development tools\n");
+            for dep in dev_dependencies {
+                requirements.push_str(&format!("{}\n", dep));
+            }
+        }
         
-        fs::write(
-            self.project_path.join("requirements.txt"),
-            requirements,
-        ).context("Failed to create requirements.txt")?;
+        self.files.write("requirements.txt", requirements)?;
         
         Ok(())
     }
@@ -229,181 +638,850 @@ local_test/
 temp/
 notes/
 "#;
+        let gitignore = self.options.gitignore.as_deref().unwrap_or(gitignore);
         
-        fs::write(
-            self.project_path.join(".gitignore"),
-            gitignore,
-        ).context("Failed to create .gitignore")?;
+        self.files.write(".gitignore", gitignore)?;
         
         Ok(())
     }
     
-    fn create_server_file(&self) -> Result<()> {
-        let server_code = r#"#!/usr/bin/env python3
-from typing import Any
-import httpx
-import sys
-import json
-from mcp.server.fastmcp import FastMCP
-
-# Initialize FastMCP server
-mcp = FastMCP("weather")
-
-# Constants
-NWS_API_BASE = "https://api.weather.gov"
-USER_AGENT = "weather-app/1.0"
-
-async def make_nws_request(url: str) -> dict[str, Any] | None:
-    """Make a request to the NWS API with proper error handling."""
-    headers = {
-        "User-Agent": USER_AGENT,
-        "Accept": "application/geo+json"
-    }
-    async with httpx.AsyncClient() as client:
-        try:
-            response = await client.get(url, headers=headers, timeout=30.0)
-            response.raise_for_status()
-            return response.json()
-        except Exception as e:
-            print(f"Error making request to {url}: {e}", file=sys.stderr)
-            return None
-
-def format_alert(feature: dict) -> str:
-    """Format an alert feature into a readable string."""
-    props = feature["properties"]
-    return f"""
-Event: {props.get('event', 'Unknown')}
-Area: {props.get('areaDesc', 'Unknown')}
-Severity: {props.get('severity', 'Unknown')}
-Description: {props.get('description', 'No description available')}
-Instructions: {props.get('instruction', 'No specific instructions provided')}
-"""
-
-@mcp.tool()
-async def get_alerts(state: str) -> str:
-    """Get weather alerts for a US state.
-
-    Args:
-        state: Two-letter US state code (e.g. CA, NY)
-    """
-    url = f"{NWS_API_BASE}/alerts/active/area/{state}"
-    data = await make_nws_request(url)
-
-    if not data or "features" not in data:
-        return "Unable to fetch alerts or no alerts found."
-
-    if not data["features"]:
-        return "No active alerts for this state."
-
-    alerts = [format_alert(feature) for feature in data["features"]]
-    return "\n---\n".join(alerts)
-
-@mcp.tool()
-async def get_forecast(latitude: float, longitude: float) -> str:
-    """Get weather forecast for a location.
-
-    Args:
-        latitude: Latitude of the location
-        longitude: Longitude of the location
-    """
-    # First get the forecast grid endpoint
-    points_url = f"{NWS_API_BASE}/points/{latitude},{longitude}"
-    points_data = await make_nws_request(points_url)
-
-    if not points_data:
-        return "Unable to fetch forecast data for this location."
-
-    # Get the forecast URL from the points response
-    forecast_url = points_data["properties"]["forecast"]
-    forecast_data = await make_nws_request(forecast_url)
+    fn create_dockerfile(&self) -> Result<()> {
+        // Poetry only exports the versions poetry.lock pins, so it stays out of the final image
+        let (export, requirements) = if self.is_poetry() {
+            (
+                r#"# Export stage: the main dependencies pinned by poetry.lock as a requirements file
+FROM python:3.12-slim AS requirements
+RUN pip install --no-cache-dir poetry poetry-plugin-export
+WORKDIR /app
+COPY pyproject.toml poetry.lock ./
+RUN poetry export --only main --output requirements.txt
+
+"#,
+                "COPY --from=requirements /app/requirements.txt ./",
+            )
+        } else {
+            ("", "COPY requirements.txt ./")
+        };
+        let build = format!(r#"{export}FROM python:3.12-slim
 
-    if not forecast_data:
-        return "Unable to fetch detailed forecast."
+# uv installs the dependencies into the image's Python, so no virtual environment is needed
+COPY --from=ghcr.io/astral-sh/uv:latest /uv /usr/local/bin/uv
 
-    # Format the periods into a readable forecast
-    periods = forecast_data["properties"]["periods"]
-    forecasts = []
-    for period in periods[:5]:  # Only show next 5 periods
-        forecast = f"""
-{period['name']}:
-Temperature: {period['temperature']}°{period['temperatureUnit']}
-Wind: {period['windSpeed']} {period['windDirection']}
-Forecast: {period['detailedForecast']}
-"""
-        forecasts.append(forecast)
+WORKDIR /app
 
-    return "\n---\n".join(forecasts)
+{requirements}
+RUN uv pip install --system --no-cache -r requirements.txt
 
-async def test_mode():
-    """Run in test mode to see if the API works without Claude."""
-    print("🧪 Running in test mode to verify functionality")
-    print("Test 1: Getting weather alerts for CA")
-    alerts = await get_alerts("CA")
-    print(alerts)
+COPY . .
+"#);
+        
+        write_dockerfile(&self.files, &self.options, &build, &["python".to_string(), self.entrypoint().to_string()])
+    }
     
-    print("\nTest 2: Getting forecast for New York City (40.7128, -74.0060)")
-    forecast = await get_forecast(40.7128, -74.0060)
-    print(forecast)
+    fn create_ci(&self) -> Result<()> {
+        let (install, run) = if self.is_poetry() {
+            ("      - run: pipx install poetry\n      - run: poetry install --no-root\n", "poetry run python")
+        } else if self.is_pip() {
+            ("      - run: pip install -r requirements.txt\n", "python")
+        } else {
+            ("      - uses: astral-sh/setup-uv@v5\n      - run: uv pip install --system -r requirements.txt\n", "python")
+        };
+        let mut steps = format!(r#"      - uses: actions/setup-python@v5
+        with:
+          python-version: "{}.{}"
+{}      - run: {} {} --test
+"#, MIN_PYTHON_VERSION.0, MIN_PYTHON_VERSION.1, install, run, self.entrypoint());
+        if self.options.with_lint {
+            steps.push_str(&format!("      - run: {} -m ruff check .\n", run));
+        }
+        if self.options.with_tests {
+            steps.push_str(&format!("      - run: {} -m pytest\n", run));
+        }
+        
+        write_ci_workflow(&self.files, &self.options, &steps)
+    }
     
-    print("\n✅ Tests completed. If you see weather data above, the server is working correctly.")
-    print("To use with Claude for Desktop, follow the instructions in README.md")
-
-if __name__ == "__main__":
-    if len(sys.argv) > 1 and sys.argv[1] == "--test":
-        # Run in test mode
-        import asyncio
-        asyncio.run(test_mode())
-    else:
-        # Normal MCP server mode
-        print("Starting MCP server in stdio mode...")
-        print("⚠️  Note: The server will appear to hang, waiting for MCP protocol messages.")
-        print("⚠️  This is normal. Use Ctrl+C to exit.")
-        print("💡 To test functionality without Claude, run: python server.py --test")
-        mcp.run(transport='stdio')
+    /// Writes tests/test_server.py, testing the alert formatting and `get_alerts` with a mocked NWS request
+    fn create_tests(&self) -> Result<()> {
+        // The weather tools live in the `weather` package of multi-file projects
+        let module = self.entrypoint().trim_end_matches(".py");
+        let test = if self.options.with_multi_file {
+            EXAMPLE_TEST.replace("import server\n", "from weather import tools\n").replace("server.", "tools.").replace("(server, ", "(tools, ")
+        } else if module != "server" {
+            EXAMPLE_TEST.replace("import server\n", &format!("import {} as server\n", module))
+        } else {
+            EXAMPLE_TEST.to_string()
+        };
+        
+        self.files.write("tests/test_server.py", test)?;
+        
+        Ok(())
+    }
+    
+    /// Writes ruff.toml, with the same line length as the TypeScript projects' Prettier config
+    fn create_ruff_config(&self) -> Result<()> {
+        let ruff_toml = r#"line-length = 100
+target-version = "py310"
+
+[lint]
+# pycodestyle errors, Pyflakes, flake8-bugbear and pyupgrade
+select = ["E4", "E7", "E9", "F", "B", "UP"]
 "#;
         
-        let file_path = self.project_path.join("server.py");
-        fs::write(&file_path, server_code)
-            .context("Failed to create server.py")?;
+        self.files.write("ruff.toml", ruff_toml)?;
         
-        // Make the file executable on Unix systems
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = fs::metadata(&file_path)?.permissions();
-            perms.set_mode(0o755);  // rwxr-xr-x
-            fs::set_permissions(&file_path, perms)
-                .context("Failed to make server.py executable")?;
+        Ok(())
+    }
+    
+    /// README section on linting with ruff, if it is set up
+    fn lint_readme_section(&self) -> String {
+        if !self.options.with_lint {
+            return String::new();
         }
         
-        Ok(())
+        let ruff = if self.is_poetry() { "poetry run ruff" } else { "ruff" };
+        format!("### Linting\n\n```bash\n# Check the code with the rules in ruff.toml, fixing what can be fixed automatically\n{ruff} check --fix .\n\n# Format the code\n{ruff} format .\n```\n\n")
+    }
+    
+    /// Generated source as written, without its comments when requested
+    fn finish_source(&self, code: String) -> String {
+        if self.options.strip_comments {
+            strip_comments(&code, &Language::Python)
+        } else {
+            code
+        }
+    }
+    
+    /// README section on running the pytest suite, if one is generated
+    fn tests_readme_section(&self) -> String {
+        if !self.options.with_tests {
+            return String::new();
+        }
+        
+        format!("### Running the Tests\n\n```bash\n# Run the pytest suite in tests/\n{} -m pytest\n```\n\n", self.python_command())
+    }
+    
+    fn create_dockerignore(&self) -> Result<()> {
+        let dockerignore = r#"# Virtual environments are created inside the image
+.venv/
+venv/
+
+# Byte-compiled files and caches
+__pycache__/
+*.py[cod]
+.mypy_cache/
+.pytest_cache/
+.ruff_cache/
+
+# Distribution / packaging
+dist/
+build/
+*.egg-info/
+
+# Local environment and secrets
+.env
+.env.*
+
+# Version control and editors
+.git/
+.gitignore
+.idea/
+.vscode/
+.DS_Store
+"#;
+        
+        self.files.write(".dockerignore", dockerignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_server_file(&self) -> Result<()> {
+        let server_code = if self.options.with_multi_file {
+            self.create_weather_package()?;
+            
+            let mut imports = String::from(r#"#!/usr/bin/env python3
+import sys
+from mcp.server.fastmcp import FastMCP
+from weather.tools import get_alerts, get_forecast, register_tools
+"#);
+            if self.options.python_httpx_client_reuse {
+                imports.push_str("from weather.nws import close_http_client, http_client_lifespan\n");
+            }
+            if self.options.server_capabilities {
+                imports.push_str(&self.capabilities_import());
+            }
+            let mut registrations = String::from("# Register weather tools\nregister_tools(mcp)\n");
+            if self.options.openapi_tools.is_some() {
+                imports.push_str("from weather.openapi import register_openapi_tools\n");
+                registrations.push_str("register_openapi_tools(mcp)\n");
+            }
+            if self.options.with_graceful_degradation {
+                imports.push_str("from weather.conditions import register_conditions_tool\n");
+                registrations.push_str("register_conditions_tool(mcp)\n");
+            }
+            if self.options.with_input_validation_examples {
+                imports.push_str("from weather.validation import register_validation_examples\n");
+                registrations.push_str("register_validation_examples(mcp)\n");
+            }
+            if self.options.with_multi_tool_example {
+                imports.push_str("from weather.composition import register_forecast_composition\n");
+                registrations.push_str("register_forecast_composition(mcp)\n");
+            }
+            if self.options.with_resources {
+                imports.push_str("from weather.resources import register_resources\n");
+                registrations.push_str("register_resources(mcp)\n");
+            }
+            if self.options.with_pagination_example {
+                imports.push_str("from weather.pagination import register_pagination_example\n");
+                registrations.push_str("register_pagination_example(mcp)\n");
+            }
+            if self.options.with_prompts {
+                imports.push_str("from weather.prompts import register_prompts\n");
+                registrations.push_str("register_prompts(mcp)\n");
+            }
+            if self.options.with_completion_example {
+                imports.push_str("from weather.completion import register_completion_example\n");
+                registrations.push_str("register_completion_example(mcp)\n");
+            }
+            
+            let mut sections = vec![
+                imports,
+                self.server_instance(),
+                registrations,
+            ];
+            if let Some(transport) = self.transport_section() {
+                sections[0].push_str("import os\n");
+                sections[0].push_str(self.transport_imports());
+                sections.push(transport);
+            }
+            if self.options.with_otel {
+                sections[0] = with_otel_imports(&sections[0]);
+                sections.insert(2, self.telemetry());
+            }
+            sections.push(self.server_main());
+            sections.join("\n")
+        } else {
+            let mut imports = if self.is_minimal() {
+                MINIMAL_SERVER_IMPORTS.to_string()
+            } else {
+                SERVER_IMPORTS.replace("import httpx\n", self.http_imports())
+            };
+            if self.options.python_typing_strict {
+                imports = imports.replace("from typing import Any\n", "from typing import Any, TypedDict, cast\n");
+            }
+            if !self.is_minimal() {
+                imports = imports.replace("import sys\n", &format!("import sys\n{}", self.nws_json_import()));
+            }
+            let mut sections = if self.is_minimal() {
+                vec![self.server_instance(), ECHO_TOOL.to_string()]
+            } else {
+                vec![
+                    self.server_instance(),
+                    NWS_CONSTANTS.to_string(),
+                    self.nws_helpers(),
+                    self.weather_tools(),
+                ]
+            };
+            if let Some((client_imports, client)) = self.http_client() {
+                // FastMCP takes the lifespan when it is created, so the client comes first
+                imports.push_str(client_imports);
+                sections.insert(0, client.to_string());
+            }
+            if self.options.tool_error_handling {
+                imports.push_str(TOOL_ERROR_IMPORT);
+            }
+            if self.options.server_capabilities {
+                imports.push_str(&self.capabilities_import());
+            }
+            if self.options.with_graceful_degradation {
+                imports.push_str("import os\n");
+            }
+            if let Some(openapi) = &self.options.openapi_tools {
+                if self.is_minimal() {
+                    imports.push_str("from typing import Any\n");
+                    imports.push_str(self.http_imports());
+                }
+                imports.push_str(OPENAPI_IMPORTS);
+                sections.push(format!(
+                    "# Tools generated from the OpenAPI spec\n{}\n{}\n{}",
+                    openapi_constants(openapi),
+                    self.openapi_request_helper(),
+                    openapi_tool_functions(openapi)
+                ));
+            }
+            if self.options.with_graceful_degradation {
+                sections.push(format!("{}\nregister_conditions_tool(mcp)\n", self.conditions_tool()));
+            }
+            if self.options.with_input_validation_examples {
+                imports.push_str(VALIDATION_IMPORTS);
+                sections.push(format!("{}\nregister_validation_examples(mcp)\n", self.validation_tool()));
+            }
+            if self.options.with_multi_tool_example {
+                sections.push(format!("{}\nregister_forecast_composition(mcp)\n", self.composition_tools()));
+            }
+            if self.options.with_resources {
+                if !imports.contains("import json\n") {
+                    imports.push_str("import json\n");
+                }
+                sections.push(format!("{}\nregister_resources(mcp)\n", self.resources()));
+            }
+            if self.options.with_pagination_example {
+                imports.push_str(PAGINATION_IMPORTS);
+                sections.push(format!("{}\nregister_pagination_example(mcp)\n", PAGINATION_EXAMPLE));
+            }
+            if self.options.with_prompts {
+                sections.push(format!("{}\nregister_prompts(mcp)\n", PROMPTS));
+            }
+            if self.options.with_completion_example {
+                imports.push_str(COMPLETION_IMPORTS);
+                sections.push(format!("{}\nregister_completion_example(mcp)\n", COMPLETION_EXAMPLE));
+            }
+            if let Some(transport) = self.transport_section() {
+                if !imports.contains("import os\n") {
+                    imports.push_str("import os\n");
+                }
+                imports.push_str(self.transport_imports());
+                sections.push(transport);
+            }
+            if self.options.with_otel {
+                imports = with_otel_imports(&imports);
+                sections.insert(1, self.telemetry());
+            }
+            sections.insert(0, imports);
+            sections.push(self.server_main());
+            sections.join("\n")
+        };
+        let server_code = server_code.replace(DEFAULT_WEATHER_API, self.options.weather_api());
+        
+        let server_code = server_code.replace(DEFAULT_ENTRYPOINT, self.entrypoint());
+        
+        self.files.write(self.entrypoint(), self.finish_source(server_code))?;
+        
+        // Make the file executable on Unix systems
+        self.files.set_executable(self.entrypoint())?;
+        
+        Ok(())
+    }
+    
+    /// Command creating the virtual environment
+    fn venv_command(&self) -> Vec<String> {
+        let command: &[&str] = if self.is_pip() {
+            &[SYSTEM_PYTHON, "-m", "venv", ".venv"]
+        } else {
+            &["uv", "venv"]
+        };
+        command.iter().map(|arg| arg.to_string()).collect()
+    }
+    
+    /// Command installing the dependencies into the virtual environment
+    fn install_command(&self) -> Vec<String> {
+        let installer: &[&str] = if self.is_pip() { &[VENV_PIP] } else { &["uv", "pip"] };
+        let mut command: Vec<String> = installer
+            .iter()
+            .chain(["install", "-r", "requirements.txt"].iter())
+            .map(|arg| arg.to_string())
+            .collect();
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Installs exactly what the lockfile pins (`uv sync --locked`, or `poetry install`
+    /// which refuses to run when poetry.lock is out of date)
+    fn install_from_lockfile(&self) -> Result<()> {
+        if self.is_pip() {
+            bail!("--frozen-lockfile is not supported with pip, which has no lockfile");
+        }
+        
+        let (lockfile, args): (&str, &[&str]) = if self.is_poetry() {
+            ("poetry.lock", &["poetry", "install"])
+        } else {
+            ("uv.lock", &["uv", "sync", "--locked"])
+        };
+        if !self.project_path.join(lockfile).exists() {
+            bail!("--frozen-lockfile needs an existing {} in the project", lockfile);
+        }
+        
+        let mut sync_command: Vec<String> = args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        sync_command.extend(self.options.extra_install_args.iter().cloned());
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&sync_command])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
+        println!("📦 Installing dependencies from {} with {}...", lockfile, sync_command[0]);
+        let output = Command::new(&sync_command[0])
+            .args(&sync_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context(format!("Failed to install dependencies with {}", shell_words::join(&sync_command)))?;
+        
+        if !output.status.success() {
+            bail!(
+                "'{}' failed:\n{}",
+                shell_words::join(&sync_command),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        println!("✅ Dependencies installed successfully");
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks that the MCP SDK can actually be imported from the virtual environment
+    fn verify_sdk_resolves(&self) -> Result<()> {
+        println!("🔍 Verifying the MCP SDK can be imported...");
+        
+        let output = Command::new(self.program(VENV_PYTHON))
+            .args(["-c", "import mcp.server.fastmcp"])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to run the virtual environment's Python to verify the MCP SDK")?;
+        
+        if !output.status.success() {
+            bail!(
+                "The MCP SDK does not resolve after installation:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        
+        println!("✅ MCP SDK resolves correctly");
+        Ok(())
+    }
+    
+    /// FastMCP construction using the configured server name
+    fn server_instance(&self) -> String {
+        let lifespan = if self.options.python_httpx_client_reuse { ", lifespan=http_client_lifespan" } else { "" };
+        let instance = SERVER_INSTANCE.replace("FastMCP(\"weather\")", &format!("FastMCP({}{})", py_string(self.options.server_name()), lifespan));
+        if !self.options.server_capabilities {
+            return instance;
+        }
+        
+        let capabilities = self.options.capabilities().iter()
+            .map(|capability| format!("{}={}()", capability, capability_class(capability)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}\n{}", instance, DECLARED_CAPABILITIES.replace("tools=ToolsCapability()", &capabilities))
+    }
+    
+    /// Import of the capability types named in the explicit declaration
+    fn capabilities_import(&self) -> String {
+        let classes: Vec<_> = self.options.capabilities().iter()
+            .map(|capability| capability_class(capability))
+            .collect();
+        format!("from mcp.types import ServerCapabilities, {}\n", classes.join(", "))
+    }
+    
+    /// Imports and definition of the shared httpx client, if requests reuse one
+    fn http_client(&self) -> Option<(&'static str, &'static str)> {
+        self.options.python_httpx_client_reuse.then_some((HTTP_CLIENT_IMPORTS, HTTP_CLIENT))
+    }
+    
+    /// OpenTelemetry setup wrapping `mcp.tool`, named after the server
+    fn telemetry(&self) -> String {
+        TELEMETRY.replace("\"weather\"", &py_string(self.options.server_name()))
+    }
+    
+    /// Weather tools, raising `ToolError` on failures when requested so clients see `isError`
+    fn weather_tools(&self) -> String {
+        let mut tools = WEATHER_TOOLS.to_string();
+        if self.options.python_typing_strict {
+            for (untyped, typed) in TYPED_NWS_CALLS {
+                tools = tools.replace(untyped, typed);
+            }
+        }
+        if self.options.tool_error_handling {
+            tools = raise_tool_errors(&tools);
+        }
+        tools
+    }
+    
+    /// Optional tool registered only when its API key is set, raising `ToolError` when requested
+    fn conditions_tool(&self) -> String {
+        if self.options.tool_error_handling {
+            raise_tool_errors(CONDITIONS_TOOL)
+        } else {
+            CONDITIONS_TOOL.to_string()
+        }
+    }
+    
+    /// Example tool with a richer input schema, raising `ToolError` when requested
+    fn validation_tool(&self) -> String {
+        if self.options.tool_error_handling {
+            raise_tool_errors(VALIDATION_TOOL)
+        } else {
+            VALIDATION_TOOL.to_string()
+        }
+    }
+    
+    /// Example tools sharing a forecast helper, raising `ToolError` when requested
+    fn composition_tools(&self) -> String {
+        if self.options.tool_error_handling {
+            raise_tool_errors(COMPOSITION_TOOLS)
+        } else {
+            COMPOSITION_TOOLS.to_string()
+        }
+    }
+    
+    /// Sample resource describing the server, named after it
+    fn resources(&self) -> String {
+        RESOURCES.replace("\"name\": \"weather\"", &format!("\"name\": {}", py_string(self.options.server_name())))
+    }
+    
+    /// Listener code for network transports
+    fn transport_section(&self) -> Option<String> {
+        let section = match self.options.transport {
+            Transport::Stdio => return None,
+            Transport::Ws => WS_SERVER.replace(&DEFAULT_PORT.to_string(), &self.options.port().to_string()),
+            Transport::Sse => SSE_SERVER.replace(&DEFAULT_PORT.to_string(), &self.options.port().to_string()),
+            Transport::Http => HTTP_SERVER.replace(&DEFAULT_HTTP_PORT.to_string(), &self.options.port().to_string()),
+        };
+        if self.options.docker {
+            // The container sets HOST so the server listens on all interfaces
+            Some(section.replace("HOST = \"127.0.0.1\"", "HOST = os.environ.get(\"HOST\", \"127.0.0.1\")"))
+        } else {
+            Some(section)
+        }
+    }
+    
+    /// Imports needed by the selected network transport
+    fn transport_imports(&self) -> &'static str {
+        match self.options.transport {
+            Transport::Ws => WS_IMPORTS,
+            Transport::Stdio | Transport::Sse | Transport::Http => "",
+        }
+    }
+    
+    /// Test mode and `__main__` block running the server on the selected transport
+    fn server_main(&self) -> String {
+        let run = match self.options.transport {
+            Transport::Stdio => STDIO_RUN,
+            Transport::Ws => WS_RUN,
+            Transport::Sse => SSE_RUN,
+            Transport::Http => HTTP_RUN,
+        };
+        let test_mode = if self.is_minimal() {
+            MINIMAL_SERVER_MAIN.to_string()
+        } else if self.options.python_httpx_client_reuse {
+            // Test mode runs the tools outside any session, so it closes the shared client itself
+            SERVER_MAIN.replace("    print(forecast)\n", "    print(forecast)\n    await close_http_client()\n")
+        } else {
+            SERVER_MAIN.to_string()
+        };
+        let server_main = format!("{}{}", test_mode, run);
+        if self.options.python_entry_console_script {
+            // Console scripts call a function, so the __main__ dispatch moves into main()
+            format!("{}\nif __name__ == \"__main__\":\n    main()\n", server_main.replace(MAIN_GUARD, MAIN_FUNCTION))
+        } else {
+            server_main
+        }
+    }
+    
+    /// Imports for the HTTP client used by the NWS helpers
+    fn http_imports(&self) -> &'static str {
+        if self.options.minimal_deps {
+            "import asyncio\nimport urllib.request\n"
+        } else {
+            "import httpx\n"
+        }
+    }
+    
+    /// Import of `json`, which only the stdlib NWS request helper parses responses with
+    fn nws_json_import(&self) -> &'static str {
+        if self.options.minimal_deps {
+            "import json\n"
+        } else {
+            ""
+        }
+    }
+    
+    /// NWS request and formatting helpers, using the stdlib when minimal dependencies are requested
+    fn nws_helpers(&self) -> String {
+        let request = if self.options.minimal_deps {
+            NWS_REQUEST_STDLIB
+        } else if self.options.python_httpx_client_reuse {
+            NWS_REQUEST_SHARED
+        } else {
+            NWS_REQUEST
+        };
+        
+        if self.options.python_typing_strict {
+            [NWS_TYPES, request, &ALERT_FORMATTER.replace("feature: dict", "feature: AlertFeature")].join("\n")
+        } else {
+            [request, ALERT_FORMATTER].join("\n")
+        }
+    }
+    
+    /// Helper performing the GET requests of the OpenAPI tools
+    fn openapi_request_helper(&self) -> &'static str {
+        if self.options.minimal_deps {
+            OPENAPI_REQUEST_STDLIB
+        } else {
+            OPENAPI_REQUEST
+        }
+    }
+    
+    /// Writes the `weather` package holding the NWS helpers and tool definitions
+    fn create_weather_package(&self) -> Result<()> {
+        let mut nws_sections = vec![
+            format!(r#""""Helpers for talking to the National Weather Service API."""
+from typing import Any{}
+{}import sys
+{}{}"#, if self.options.python_typing_strict { ", TypedDict" } else { "" }, self.nws_json_import(), self.http_imports(), self.http_client().map_or("", |(imports, _)| imports)),
+            NWS_CONSTANTS.to_string(),
+            self.nws_helpers(),
+        ];
+        if let Some((_, client)) = self.http_client() {
+            nws_sections.insert(2, client.to_string());
+        }
+        let nws_code = nws_sections.join("\n");
+        let nws_code = nws_code.replace(DEFAULT_WEATHER_API, self.options.weather_api());
+        
+        let tool_error_import = if self.options.tool_error_handling { TOOL_ERROR_IMPORT } else { "" };
+        let (typing_import, response_types) = if self.options.python_typing_strict {
+            ("from typing import cast\n\n", "AlertsResponse, ForecastResponse, PointsResponse, ")
+        } else {
+            ("", "")
+        };
+        let tools_code = [
+            &format!(r#""""Weather tools exposed by the MCP server."""
+{}from mcp.server.fastmcp import FastMCP
+{}
+from .nws import NWS_API_BASE, {}format_alert, make_nws_request
+"#, typing_import, tool_error_import, response_types),
+            &self.weather_tools().replace("@mcp.tool()\n", ""),
+            r#"def register_tools(mcp: FastMCP) -> None:
+    """Register the weather tools on the given server."""
+    mcp.tool()(get_alerts)
+    mcp.tool()(get_forecast)
+"#,
+        ].join("\n");
+        
+        let mut files = vec![
+            ("weather/__init__.py", "\"\"\"Weather tools for the MCP server.\"\"\"\n".to_string()),
+            ("weather/nws.py", nws_code),
+            ("weather/tools.py", tools_code),
+        ];
+        
+        if let Some(openapi) = &self.options.openapi_tools {
+            let registrations: String = openapi.operations.iter()
+                .map(|operation| format!("    mcp.tool()({})\n", tool_name(&operation.name)))
+                .collect();
+            let openapi_code = [
+                format!(r#""""Tools generated from the OpenAPI spec."""
+from typing import Any
+{}{}from mcp.server.fastmcp import FastMCP
+"#, self.http_imports(), OPENAPI_IMPORTS),
+                openapi_constants(openapi),
+                self.openapi_request_helper().to_string(),
+                openapi_tool_functions(openapi).replace("@mcp.tool()\n", ""),
+                format!(r#"def register_openapi_tools(mcp: FastMCP) -> None:
+    """Register the OpenAPI tools on the given server."""
+{}"#, registrations),
+            ].join("\n");
+            files.push(("weather/openapi.py", openapi_code));
+        }
+        
+        if self.options.with_graceful_degradation {
+            let conditions_code = format!(r#""""Optional tools that are only registered when their API keys are set."""
+import os
+import sys
+from mcp.server.fastmcp import FastMCP
+{}
+from .nws import make_nws_request
+
+{}"#, tool_error_import, self.conditions_tool());
+            files.push(("weather/conditions.py", conditions_code));
+        }
+        
+        if self.options.with_input_validation_examples {
+            let validation_code = format!(r#""""Example tool showing richer input validation with pydantic."""
+{}from mcp.server.fastmcp import FastMCP
+{}
+from .nws import NWS_API_BASE, format_alert, make_nws_request
+
+{}"#, VALIDATION_IMPORTS, tool_error_import, self.validation_tool());
+            files.push(("weather/validation.py", validation_code));
+        }
+        
+        if self.options.with_multi_tool_example {
+            let composition_code = format!(r#""""Example tools composed from a shared forecast helper."""
+from typing import Any
+from mcp.server.fastmcp import FastMCP
+{}
+from .nws import NWS_API_BASE, make_nws_request
+
+{}"#, tool_error_import, self.composition_tools());
+            files.push(("weather/composition.py", composition_code));
+        }
+        
+        if self.options.with_resources {
+            let resources_code = format!(r#""""Resources exposed by the MCP server."""
+import json
+from mcp.server.fastmcp import FastMCP
+
+{}"#, self.resources());
+            files.push(("weather/resources.py", resources_code));
+        }
+        
+        if self.options.with_pagination_example {
+            let pagination_code = format!(r#""""Resources listed a page at a time."""
+{}from mcp.server.fastmcp import FastMCP
+
+{}"#, PAGINATION_IMPORTS, PAGINATION_EXAMPLE);
+            files.push(("weather/pagination.py", pagination_code));
+        }
+        
+        if self.options.with_prompts {
+            let prompts_code = format!(r#""""Prompt templates exposed by the MCP server."""
+from mcp.server.fastmcp import FastMCP
+
+{}"#, PROMPTS);
+            files.push(("weather/prompts.py", prompts_code));
+        }
+        
+        if self.options.with_completion_example {
+            let completion_code = format!(r#""""Prompt with an argument clients can autocomplete."""
+from mcp.server.fastmcp import FastMCP
+{}
+{}"#, COMPLETION_IMPORTS, COMPLETION_EXAMPLE);
+            files.push(("weather/completion.py", completion_code));
+        }
+        
+        for (path, content) in files {
+            self.files.write(path, self.finish_source(content))?;
+        }
+        
+        Ok(())
     }
     
     fn create_readme(&self) -> Result<()> {
+        let project_dir = format!("/ABSOLUTE/PATH/TO/{}", self.options.directory_name(&self.project_name));
+        let (command, run_args, prerequisite, installation) = if self.is_poetry() {
+            (
+                "poetry".to_string(),
+                vec!["--directory", &project_dir, "run", "python"],
+                "Poetry",
+                "# Create the virtual environment and install dependencies\npoetry install",
+            )
+        } else if self.is_pip() {
+            (
+                format!("{}/.venv/bin/python", project_dir),
+                vec![],
+                "pip",
+                "# Create and activate virtual environment\npython3 -m venv .venv\nsource .venv/bin/activate  # On Windows: .venv\\Scripts\\activate\n\n# Install dependencies\npip install -r requirements.txt",
+            )
+        } else {
+            (
+                "uv".to_string(),
+                vec!["--directory", &project_dir, "run"],
+                "uv",
+                "# Create and activate virtual environment\nuv venv\nsource .venv/bin/activate  # On Windows: .venv\\Scripts\\activate\n\n# Install dependencies\nuv pip install -r requirements.txt",
+            )
+        };
+        // pip projects have no runner, so the entrypoint needs its absolute path
+        let entrypoint = if self.is_pip() {
+            format!("{}/{}", project_dir, self.entrypoint())
+        } else {
+            self.entrypoint().to_string()
+        };
+        let args = run_args
+            .iter()
+            .copied()
+            .chain([entrypoint.as_str()])
+            .map(|arg| format!("        {}", serde_json::to_string(arg).unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        
+        let mut extra_tools = String::new();
+        if self.options.with_graceful_degradation {
+            extra_tools.push_str(CONDITIONS_README_ENTRY);
+        }
+        if self.options.with_input_validation_examples {
+            extra_tools.push_str(VALIDATION_README_ENTRY);
+        }
+        if self.options.with_multi_tool_example {
+            extra_tools.push_str(COMPOSITION_README_ENTRY);
+        }
+        if self.options.with_resources {
+            extra_tools.push_str(RESOURCES_README_ENTRY);
+        }
+        if self.options.with_pagination_example {
+            extra_tools.push_str(PAGINATION_README_ENTRY);
+        }
+        if self.options.with_prompts {
+            extra_tools.push_str(PROMPTS_README_ENTRY);
+        }
+        if self.options.with_completion_example {
+            extra_tools.push_str(COMPLETION_README_ENTRY);
+        }
+        if let Some(openapi) = &self.options.openapi_tools {
+            extra_tools.push_str(&openapi.readme_entries());
+        }
+        
+        let integration = if self.options.skip_readme_integration {
+            String::new()
+        } else {
+            format!(r#"### Integration with Claude for Desktop
+
+To integrate with Claude for Desktop, you'll need to configure the MCP server in Claude's configuration file. 
+
+Open `~/Library/Application Support/Claude/claude_desktop_config.json` (create it if it doesn't exist) and add:
+
+```json
+{{
+  "mcpServers": {{
+    {}: {{
+      "command": {},
+      "args": [
+{}
+      ]
+    }}
+  }}
+}}
+```
+
+Replace `{}` with the absolute path to your project directory.
+
+Once configured, restart Claude for Desktop, and you should see the weather tools appear in the tools menu.
+
+"#, serde_json::to_string(self.options.server_name()).unwrap_or_default(), serde_json::to_string(&command).unwrap_or_default(), args, project_dir)
+        };
+        
+        let integration = integration + &http_readme_section(&self.options) + &inspector_readme_section(&self.options)
+            + &docker_readme_section(&self.options, &self.project_name);
+        let (about, test_output, tools, examples, troubleshooting) = if self.is_minimal() {
+            (MINIMAL_README_ABOUT, MINIMAL_README_TEST_OUTPUT, MINIMAL_README_TOOLS, MINIMAL_README_EXAMPLES, "")
+        } else {
+            (WEATHER_README_ABOUT, WEATHER_README_TEST_OUTPUT, WEATHER_README_TOOLS, WEATHER_README_EXAMPLES, WEATHER_README_TROUBLESHOOTING)
+        };
+        
         let readme = format!(r#"# {}
 
 A Model Context Protocol (MCP) server implementation in Python.
 
 ## About
 
-This project implements an MCP server that provides weather information via the National Weather Service API. It demonstrates how to create a server that can be used with MCP compatible clients like Claude for Desktop.
+{}
 
 ## Getting Started
 
 ### Prerequisites
 
 - Python 3.10 or newer
-- uv (Python package manager)
+- {} (Python package manager)
 
 ### Installation
 
 ```bash
-# Create and activate virtual environment
-uv venv
-source .venv/bin/activate  # On Windows: .venv\Scripts\activate
-
-# Install dependencies
-uv pip install -r requirements.txt
+{}
 ```
 
 ### Testing the Server
@@ -411,77 +1489,1023 @@ uv pip install -r requirements.txt
 To test the server functionality without Claude for Desktop:
 
 ```bash
-python server.py --test
+{} {} --test
 ```
 
-This will run the server in test mode and display weather alerts for California and a forecast for New York City.
+This will run the server in test mode and {}.
 
-### Running the Server
+{}### Running the Server
 
 **Important Note:** When running in normal mode, this server is designed to be used with Claude for Desktop or other MCP clients. 
-When you run `python server.py` directly, it will appear to hang because it's waiting for MCP protocol 
+When you run `{} {}` directly, it will appear to hang because it's waiting for MCP protocol 
 messages via stdin. This is expected behavior - you should not run it in this mode for interactive use.
 
-### Integration with Claude for Desktop
-
-To integrate with Claude for Desktop, you'll need to configure the MCP server in Claude's configuration file. 
-
-Open `~/Library/Application Support/Claude/claude_desktop_config.json` (create it if it doesn't exist) and add:
-
-```json
-{{
-  "mcpServers": {{
-    "weather": {{
-      "command": "uv",
-      "args": [
-        "--directory",
-        "/ABSOLUTE/PATH/TO/{}",
-        "run",
-        "server.py"
-      ]
-    }}
-  }}
-}}
-```
-
-Replace `/ABSOLUTE/PATH/TO/{}` with the absolute path to your project directory.
-
-Once configured, restart Claude for Desktop, and you should see the weather tools appear in the tools menu.
-
-## Available Tools
+{}## Available Tools
 
 This MCP server provides the following tools:
 
-- **get_alerts**: Get active weather alerts for a US state
-  - Parameters: `state` (two-letter state code)
-
-- **get_forecast**: Get weather forecast for a location
-  - Parameters: `latitude`, `longitude`
-
-## Example Queries for Claude
+{}{}## Example Queries for Claude
 
 After connecting your server to Claude for Desktop, you can ask questions like:
 
-- "What are the active weather alerts in California?"
-- "What's the weather forecast for New York? (coordinates: 40.7128, -74.0060)"
-
+{}
 ## Troubleshooting
 
 - **Server appears to hang in normal mode**: This is normal. The server is waiting for MCP protocol messages on stdin.
 - **No tools appear in Claude**: Make sure the paths in `claude_desktop_config.json` are correct and absolute. Restart Claude for Desktop.
 - **Error in Claude's logs**: Check `~/Library/Logs/Claude/mcp*.log` for errors.
-- **API errors**: If you're getting errors with the weather API, try using test mode to see detailed error messages.
+{}{}"#,
+            self.project_name,
+            about,
+            prerequisite,
+            installation,
+            self.python_command(),
+            self.entrypoint(),
+            test_output,
+            self.tests_readme_section() + &self.lint_readme_section(),
+            self.python_command(),
+            self.entrypoint(),
+            integration,
+            tools,
+            extra_tools,
+            examples,
+            troubleshooting,
+            license::readme_section(&self.options.license)
+        );
+        
+        self.files.write("README.md", readme)?;
+        
+        Ok(())
+    }
+    
+    /// Writes QUICKSTART.md with just the commands to install, test and run the server
+    fn create_quickstart(&self) -> Result<()> {
+        let install = if self.is_poetry() {
+            "poetry install"
+        } else if self.is_pip() {
+            "python3 -m venv .venv\nsource .venv/bin/activate  # On Windows: .venv\\Scripts\\activate\npip install -r requirements.txt"
+        } else {
+            "uv venv\nsource .venv/bin/activate  # On Windows: .venv\\Scripts\\activate\nuv pip install -r requirements.txt"
+        };
+        
+        let quickstart = format!(r#"# {} Quickstart
 
-## License
+Copy and paste these commands to get the server running. See README.md for details.
 
-MIT
-"#, self.project_name, self.project_name, self.project_name);
+```bash
+cd {}
+{}
+{} {} --test
+{} {}
+```
+"#,
+            self.project_name,
+            self.options.directory(&self.project_name),
+            install,
+            self.python_command(),
+            self.entrypoint(),
+            self.python_command(),
+            self.entrypoint()
+        );
         
-        fs::write(
-            self.project_path.join("README.md"),
-            readme,
-        ).context("Failed to create README.md")?;
+        self.files.write("QUICKSTART.md", quickstart)?;
         
         Ok(())
     }
-} 
\ No newline at end of file
+}
+
+/// Adds the imports of the OpenTelemetry setup that `imports` does not have yet
+fn with_otel_imports(imports: &str) -> String {
+    let mut imports = imports.to_string();
+    for import in ["import os\n", "from typing import Any\n"] {
+        if !imports.contains(import.trim_end()) {
+            imports.push_str(import);
+        }
+    }
+    imports.push_str(OTEL_IMPORTS);
+    imports
+}
+
+/// Splits a PEP 508 requirement like `mcp[cli]>=1.2.0` into its name (with extras) and
+/// version constraint, which is `*` when the requirement has none
+fn split_requirement(requirement: &str) -> (&str, &str) {
+    let split = requirement.find(['<', '>', '=', '!', '~']).unwrap_or(requirement.len());
+    let (name, constraint) = requirement.split_at(split);
+    (name, if constraint.is_empty() { "*" } else { constraint })
+}
+
+/// Converts a PEP 508 requirement like `mcp[cli]>=1.2.0` into a Poetry dependency line
+fn poetry_dependency(requirement: &str) -> String {
+    let (name, constraint) = split_requirement(requirement);
+    
+    match name.split_once('[') {
+        Some((name, extras)) => format!(
+            "{} = {{ version = \"{}\", extras = [\"{}\"] }}",
+            name,
+            constraint,
+            extras.trim_end_matches(']')
+        ),
+        None => format!("{} = \"{}\"", name, constraint),
+    }
+}
+
+/// Renders the constants used by the OpenAPI tools
+fn openapi_constants(openapi: &OpenApiTools) -> String {
+    format!("API_BASE = {}\n", py_string(&openapi.base_url))
+}
+
+/// Renders an `@mcp.tool()` function for every GET operation of an OpenAPI spec
+fn openapi_tool_functions(openapi: &OpenApiTools) -> String {
+    let mut functions = Vec::new();
+    for operation in &openapi.operations {
+        // Python requires parameters without defaults to come first
+        let mut parameters: Vec<_> = operation.parameters.iter().collect();
+        parameters.sort_by_key(|parameter| !parameter.required);
+        
+        let mut signature = Vec::new();
+        let mut docs = String::new();
+        let mut query = Vec::new();
+        let mut path = operation.path.clone();
+        
+        for parameter in parameters {
+            let identifier = py_identifier(&parameter.name);
+            let annotation = match parameter.kind {
+                ParamKind::String => "str",
+                ParamKind::Integer => "int",
+                ParamKind::Number => "float",
+                ParamKind::Boolean => "bool",
+            };
+            if parameter.required {
+                signature.push(format!("{}: {}", identifier, annotation));
+            } else {
+                signature.push(format!("{}: {} | None = None", identifier, annotation));
+            }
+            docs.push_str(&format!(
+                "        {}: {}\n",
+                identifier,
+                parameter.description.as_deref().unwrap_or(&parameter.name).replace('"', "'")
+            ));
+            
+            if parameter.in_path {
+                path = path.replace(
+                    &format!("{{{}}}", parameter.name),
+                    &format!("{{quote(str({}), safe='')}}", identifier),
+                );
+            } else {
+                query.push(format!("{}: {}", py_string(&parameter.name), identifier));
+            }
+        }
+        
+        let docs = if docs.is_empty() {
+            String::new()
+        } else {
+            format!("\n\n    Args:\n{}    ", docs)
+        };
+        
+        functions.push(format!(r#"@mcp.tool()
+async def {}({}) -> str:
+    """{}{}"""
+    url = f"{{API_BASE}}{}"
+    return await call_api(url, {{{}}})
+"#, tool_name(&operation.name), signature.join(", "), operation.description.replace('"', "'"), docs, path, query.join(", ")));
+    }
+    functions.join("\n")
+}
+
+/// Name of the `mcp.types` class describing a capability, e.g. `ToolsCapability` for `tools`
+fn capability_class(capability: &str) -> String {
+    let mut chars = capability.chars();
+    let first = chars.next().map(|c| c.to_ascii_uppercase()).unwrap_or_default();
+    format!("{}{}Capability", first, chars.as_str())
+}
+
+/// Rewrites `return "Unable ..."` failure results into `raise ToolError(...)`
+fn raise_tool_errors(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            match trimmed.strip_prefix("return ") {
+                Some(message) if message.starts_with("\"Unable") => {
+                    format!("{}raise ToolError({})\n", &line[..line.len() - trimmed.len()], message)
+                },
+                _ => format!("{}\n", line),
+            }
+        })
+        .collect()
+}
+
+/// Turns an OpenAPI parameter name into a valid Python identifier
+fn py_identifier(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+        "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in",
+        "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+        "with", "yield",
+    ];
+    
+    let identifier = tool_name(name);
+    if KEYWORDS.contains(&identifier.as_str()) {
+        format!("{}_", identifier)
+    } else {
+        identifier
+    }
+}
+
+/// Quotes a value as a Python string literal
+fn py_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Extra imports needed by the OpenAPI tools
+const OPENAPI_IMPORTS: &str = "from urllib.parse import quote, urlencode\n";
+
+/// OpenAPI request helper using httpx
+const OPENAPI_REQUEST: &str = r#"async def call_api(url: str, params: dict[str, Any]) -> str:
+    """Call a GET endpoint from the OpenAPI spec and return the response body."""
+    query = {key: value for key, value in params.items() if value is not None}
+    async with httpx.AsyncClient() as client:
+        try:
+            response = await client.get(url, params=query, timeout=30.0)
+            response.raise_for_status()
+            return response.text
+        except Exception as e:
+            return f"Request to {url} failed: {e}"
+"#;
+
+/// OpenAPI request helper using only the standard library
+const OPENAPI_REQUEST_STDLIB: &str = r#"async def call_api(url: str, params: dict[str, Any]) -> str:
+    """Call a GET endpoint from the OpenAPI spec and return the response body."""
+    query = urlencode({key: value for key, value in params.items() if value is not None})
+    request_url = f"{url}?{query}" if query else url
+
+    def fetch() -> str:
+        with urllib.request.urlopen(request_url, timeout=30.0) as response:
+            return response.read().decode("utf-8")
+
+    try:
+        return await asyncio.to_thread(fetch)
+    except Exception as e:
+        return f"Request to {url} failed: {e}"
+"#;
+
+/// Shebang and imports for the single-file server
+const SERVER_IMPORTS: &str = r#"#!/usr/bin/env python3
+from typing import Any
+import httpx
+import sys
+from mcp.server.fastmcp import FastMCP
+"#;
+
+/// FastMCP server construction
+const SERVER_INSTANCE: &str = r#"# Initialize FastMCP server
+mcp = FastMCP("weather")
+"#;
+
+/// Replaces the capabilities FastMCP derives from its handlers (tools, resources and
+/// prompts, whether or not any are registered) with the ones the server implements
+const DECLARED_CAPABILITIES: &str = r#"# Declare only the capabilities this server implements; FastMCP otherwise
+# advertises tools, resources and prompts whether or not any are registered
+def declared_capabilities(*_args: object) -> ServerCapabilities:
+    return ServerCapabilities(tools=ToolsCapability())
+
+
+mcp._mcp_server.get_capabilities = declared_capabilities  # type: ignore[method-assign]
+"#;
+
+/// NWS API constants shared by the weather example
+const NWS_CONSTANTS: &str = r#"# Constants
+NWS_API_BASE = "https://api.weather.gov"
+USER_AGENT = "weather-app/1.0"
+"#;
+
+/// NWS request helper using httpx
+const NWS_REQUEST: &str = r#"async def make_nws_request(url: str) -> dict[str, Any] | None:
+    """Make a request to the NWS API with proper error handling."""
+    headers = {
+        "User-Agent": USER_AGENT,
+        "Accept": "application/geo+json"
+    }
+    async with httpx.AsyncClient() as client:
+        try:
+            response = await client.get(url, headers=headers, timeout=30.0)
+            response.raise_for_status()
+            return response.json()
+        except Exception as e:
+            print(f"Error making request to {url}: {e}", file=sys.stderr)
+            return None
+"#;
+
+/// Shared httpx client, closed by the server lifespan once the last session ends
+const HTTP_CLIENT: &str = r#"# One client shared by all NWS requests, so connections to the API are pooled and
+# reused instead of opened for every request
+http_client: httpx.AsyncClient | None = None
+open_sessions = 0
+
+
+def get_http_client() -> httpx.AsyncClient:
+    """Return the shared HTTP client, creating it on first use."""
+    global http_client
+    if http_client is None:
+        http_client = httpx.AsyncClient(timeout=30.0)
+    return http_client
+
+
+async def close_http_client() -> None:
+    """Close the shared HTTP client and its pooled connections, if it was created."""
+    global http_client
+    if http_client is not None:
+        await http_client.aclose()
+        http_client = None
+
+
+@asynccontextmanager
+async def http_client_lifespan(_server: object) -> AsyncIterator[None]:
+    """Close the shared HTTP client when the last session ends (once, over stdio)."""
+    global open_sessions
+    open_sessions += 1
+    try:
+        yield
+    finally:
+        open_sessions -= 1
+        if open_sessions == 0:
+            await close_http_client()
+"#;
+
+/// Imports of the shared httpx client's lifespan
+const HTTP_CLIENT_IMPORTS: &str = "from collections.abc import AsyncIterator\nfrom contextlib import asynccontextmanager\n";
+
+/// NWS request helper using the shared httpx client
+const NWS_REQUEST_SHARED: &str = r#"async def make_nws_request(url: str) -> dict[str, Any] | None:
+    """Make a request to the NWS API with proper error handling."""
+    headers = {
+        "User-Agent": USER_AGENT,
+        "Accept": "application/geo+json"
+    }
+    try:
+        response = await get_http_client().get(url, headers=headers)
+        response.raise_for_status()
+        return response.json()
+    except Exception as e:
+        print(f"Error making request to {url}: {e}", file=sys.stderr)
+        return None
+"#;
+
+/// NWS request helper using only the standard library
+const NWS_REQUEST_STDLIB: &str = r#"async def make_nws_request(url: str) -> dict[str, Any] | None:
+    """Make a request to the NWS API with proper error handling."""
+    headers = {
+        "User-Agent": USER_AGENT,
+        "Accept": "application/geo+json"
+    }
+    request = urllib.request.Request(url, headers=headers)
+
+    def fetch() -> dict[str, Any]:
+        with urllib.request.urlopen(request, timeout=30.0) as response:
+            return json.loads(response.read().decode("utf-8"))
+
+    try:
+        # urllib blocks, so run it in a worker thread to keep the event loop free
+        return await asyncio.to_thread(fetch)
+    except Exception as e:
+        print(f"Error making request to {url}: {e}", file=sys.stderr)
+        return None
+"#;
+
+/// Alert formatting helper
+const ALERT_FORMATTER: &str = r#"def format_alert(feature: dict) -> str:
+    """Format an alert feature into a readable string."""
+    props = feature["properties"]
+    return f"""
+Event: {props.get('event', 'Unknown')}
+Area: {props.get('areaDesc', 'Unknown')}
+Severity: {props.get('severity', 'Unknown')}
+Description: {props.get('description', 'No description available')}
+Instructions: {props.get('instruction', 'No specific instructions provided')}
+"""
+"#;
+
+/// Weather tool definitions
+const WEATHER_TOOLS: &str = r#"@mcp.tool()
+async def get_alerts(state: str) -> str:
+    """Get weather alerts for a US state.
+
+    Args:
+        state: Two-letter US state code (e.g. CA, NY)
+    """
+    url = f"{NWS_API_BASE}/alerts/active/area/{state}"
+    data = await make_nws_request(url)
+
+    if not data or "features" not in data:
+        return "Unable to fetch alerts or no alerts found."
+
+    if not data["features"]:
+        return "No active alerts for this state."
+
+    alerts = [format_alert(feature) for feature in data["features"]]
+    return "\n---\n".join(alerts)
+
+@mcp.tool()
+async def get_forecast(latitude: float, longitude: float) -> str:
+    """Get weather forecast for a location.
+
+    Args:
+        latitude: Latitude of the location
+        longitude: Longitude of the location
+    """
+    # First get the forecast grid endpoint
+    points_url = f"{NWS_API_BASE}/points/{latitude},{longitude}"
+    points_data = await make_nws_request(points_url)
+
+    if not points_data:
+        return "Unable to fetch forecast data for this location."
+
+    # Get the forecast URL from the points response
+    forecast_url = points_data["properties"]["forecast"]
+    forecast_data = await make_nws_request(forecast_url)
+
+    if not forecast_data:
+        return "Unable to fetch detailed forecast."
+
+    # Format the periods into a readable forecast
+    periods = forecast_data["properties"]["periods"]
+    forecasts = []
+    for period in periods[:5]:  # Only show next 5 periods
+        forecast = f"""
+{period['name']}:
+Temperature: {period['temperature']}°{period['temperatureUnit']}
+Wind: {period['windSpeed']} {period['windDirection']}
+Forecast: {period['detailedForecast']}
+"""
+        forecasts.append(forecast)
+
+    return "\n---\n".join(forecasts)
+"#;
+
+/// TypedDicts describing the parts of the NWS responses the weather tools read
+const NWS_TYPES: &str = r#"class AlertProperties(TypedDict, total=False):
+    event: str
+    areaDesc: str
+    severity: str
+    description: str
+    instruction: str
+
+class AlertFeature(TypedDict):
+    properties: AlertProperties
+
+class AlertsResponse(TypedDict):
+    features: list[AlertFeature]
+
+class PointsProperties(TypedDict):
+    forecast: str
+
+class PointsResponse(TypedDict):
+    properties: PointsProperties
+
+class ForecastPeriod(TypedDict):
+    name: str
+    temperature: int
+    temperatureUnit: str
+    windSpeed: str
+    windDirection: str
+    detailedForecast: str
+
+class ForecastProperties(TypedDict):
+    periods: list[ForecastPeriod]
+
+class ForecastResponse(TypedDict):
+    properties: ForecastProperties
+"#;
+
+/// NWS requests in the weather tools and their typed replacements
+const TYPED_NWS_CALLS: [(&str, &str); 3] = [
+    ("data = await make_nws_request(url)", "data = cast(AlertsResponse | None, await make_nws_request(url))"),
+    ("points_data = await make_nws_request(points_url)", "points_data = cast(PointsResponse | None, await make_nws_request(points_url))"),
+    ("forecast_data = await make_nws_request(forecast_url)", "forecast_data = cast(ForecastResponse | None, await make_nws_request(forecast_url))"),
+];
+
+/// Test mode and the `__main__` dispatch, completed by the transport run block
+const SERVER_MAIN: &str = r#"async def test_mode():
+    """Run in test mode to see if the API works without Claude."""
+    print("🧪 Running in test mode to verify functionality")
+    print("Test 1: Getting weather alerts for CA")
+    alerts = await get_alerts("CA")
+    print(alerts)
+    
+    print("\nTest 2: Getting forecast for New York City (40.7128, -74.0060)")
+    forecast = await get_forecast(40.7128, -74.0060)
+    print(forecast)
+    
+    print("\n✅ Tests completed. If you see weather data above, the server is working correctly.")
+    print("To use with Claude for Desktop, follow the instructions in README.md")
+
+if __name__ == "__main__":
+    if len(sys.argv) > 1 and sys.argv[1] == "--test":
+        # Run in test mode
+        import asyncio
+        asyncio.run(test_mode())
+    else:
+"#;
+
+/// Guard opening the `__main__` dispatch in `SERVER_MAIN`
+const MAIN_GUARD: &str = "if __name__ == \"__main__\":\n";
+
+/// Replaces `MAIN_GUARD` when the dispatch is exposed as a console script
+const MAIN_FUNCTION: &str = "def main() -> None:\n    \"\"\"Run the MCP server, or the test mode when called with --test.\"\"\"\n";
+
+/// Runs the server over stdio
+const STDIO_RUN: &str = r#"        # Normal MCP server mode
+        print("Starting MCP server in stdio mode...")
+        print("⚠️  Note: The server will appear to hang, waiting for MCP protocol messages.")
+        print("⚠️  This is normal. Use Ctrl+C to exit.")
+        print("💡 To test functionality without Claude, run: python server.py --test")
+        mcp.run(transport='stdio')
+"#;
+
+/// Runs the server as a WebSocket listener
+const WS_RUN: &str = r#"        print(f"Starting MCP server on ws://{HOST}:{PORT}")
+        print("💡 To test functionality without Claude, run: python server.py --test")
+        uvicorn.run(websocket_app, host=HOST, port=PORT, lifespan="off")
+"#;
+
+/// Runs the server with FastMCP's SSE transport on the configured host and port
+const SSE_RUN: &str = r#"        print(f"Starting MCP server on http://{HOST}:{PORT}/sse")
+        print("💡 To test functionality without Claude, run: python server.py --test")
+        mcp.settings.host = HOST
+        mcp.settings.port = PORT
+        mcp.run(transport='sse')
+"#;
+
+/// Runs the server with FastMCP's Streamable HTTP transport on the configured host and port
+const HTTP_RUN: &str = r#"        print(f"Starting MCP server on http://{HOST}:{PORT}/mcp")
+        print("💡 To test functionality without Claude, run: python server.py --test")
+        mcp.settings.host = HOST
+        mcp.settings.port = PORT
+        mcp.run(transport='streamable-http')
+"#;
+
+/// Imports of the minimal template's server file
+const MINIMAL_SERVER_IMPORTS: &str = r#"#!/usr/bin/env python3
+import sys
+from mcp.server.fastmcp import FastMCP
+"#;
+
+/// The only tool of the minimal template
+const ECHO_TOOL: &str = r#"@mcp.tool()
+async def echo(message: str) -> str:
+    """Echo back the given message.
+
+    Args:
+        message: Message to echo back
+    """
+    return message
+"#;
+
+/// Test mode and `__main__` dispatch for the minimal template
+const MINIMAL_SERVER_MAIN: &str = r#"async def test_mode():
+    """Run in test mode to check the tools work without Claude."""
+    print("🧪 Running in test mode to verify functionality")
+    print("Test 1: Echoing a message")
+    print(await echo("Hello, MCP!"))
+    
+    print("\n✅ Tests completed. If you see the message above, the server is working correctly.")
+    print("To use with Claude for Desktop, follow the instructions in README.md")
+
+if __name__ == "__main__":
+    if len(sys.argv) > 1 and sys.argv[1] == "--test":
+        # Run in test mode
+        import asyncio
+        asyncio.run(test_mode())
+    else:
+"#;
+
+/// README description of the weather example
+const WEATHER_README_ABOUT: &str = "This project implements an MCP server that provides weather information via the National Weather Service API. It demonstrates how to create a server that can be used with MCP compatible clients like Claude for Desktop.";
+
+/// What the weather example's test mode shows
+const WEATHER_README_TEST_OUTPUT: &str = "display weather alerts for California and a forecast for New York City";
+
+/// README entries for the weather tools
+const WEATHER_README_TOOLS: &str = "- **get_alerts**: Get active weather alerts for a US state\n  - Parameters: `state` (two-letter state code)\n\n- **get_forecast**: Get weather forecast for a location\n  - Parameters: `latitude`, `longitude`\n\n";
+
+/// README example queries for the weather tools
+const WEATHER_README_EXAMPLES: &str = "- \"What are the active weather alerts in California?\"\n- \"What's the weather forecast for New York? (coordinates: 40.7128, -74.0060)\"\n";
+
+/// README troubleshooting entry for the weather API
+const WEATHER_README_TROUBLESHOOTING: &str = "- **API errors**: If you're getting errors with the weather API, try using test mode to see detailed error messages.\n";
+
+/// README description of the minimal template
+const MINIMAL_README_ABOUT: &str = "This project implements a minimal MCP server with a single `echo` tool, ready to be extended with your own tools. It can be used with MCP compatible clients like Claude for Desktop.";
+
+/// What the minimal template's test mode shows
+const MINIMAL_README_TEST_OUTPUT: &str = "echo a test message";
+
+/// README entry for the echo tool
+const MINIMAL_README_TOOLS: &str = "- **echo**: Echo back the given message\n  - Parameters: `message`\n\n";
+
+/// README example query for the echo tool
+const MINIMAL_README_EXAMPLES: &str = "- \"Use the echo tool to repeat 'Hello, MCP!'\"\n";
+
+/// Optional tool showing how to skip registration when an API key is missing
+const CONDITIONS_TOOL: &str = r#"def register_conditions_tool(mcp: FastMCP) -> None:
+    """Register get_current_conditions only when OPENWEATHER_API_KEY is set.
+
+    The server still starts (without the tool) when the key is missing.
+    """
+    api_key = os.environ.get("OPENWEATHER_API_KEY")
+    if not api_key:
+        print("OPENWEATHER_API_KEY is not set; skipping the get_current_conditions tool", file=sys.stderr)
+        return
+
+    @mcp.tool()
+    async def get_current_conditions(latitude: float, longitude: float) -> str:
+        """Get current weather conditions for a location.
+
+        Args:
+            latitude: Latitude of the location
+            longitude: Longitude of the location
+        """
+        url = f"https://api.openweathermap.org/data/2.5/weather?lat={latitude}&lon={longitude}&units=metric&appid={api_key}"
+        data = await make_nws_request(url)
+
+        if not data:
+            return "Unable to fetch current conditions."
+
+        description = (data.get("weather") or [{}])[0].get("description", "Unknown")
+        main = data.get("main", {})
+        return f"""
+Current conditions for {latitude}, {longitude}:
+Conditions: {description}
+Temperature: {main.get('temp', 'Unknown')}°C
+Humidity: {main.get('humidity', 'Unknown')}%
+"""
+"#;
+
+/// README entry for the optional conditions tool
+const CONDITIONS_README_ENTRY: &str = "- **get_current_conditions**: Get current weather conditions for a location (only registered when `OPENWEATHER_API_KEY` is set)\n  - Parameters: `latitude`, `longitude`\n\n";
+
+/// Imports used by the input validation example
+const VALIDATION_IMPORTS: &str = "from typing import Annotated, Literal\nfrom pydantic import AfterValidator, Field\n";
+
+/// Example tool whose signature shows pydantic enums, refinements and defaults
+const VALIDATION_TOOL: &str = r#"def not_blank(value: str) -> str:
+    """Strip surrounding whitespace, rejecting keywords that are too short."""
+    value = value.strip()
+    if len(value) < 3:
+        raise ValueError("use at least 3 non-blank characters")
+    return value
+
+
+def register_validation_examples(mcp: FastMCP) -> None:
+    """Register search_alerts, whose signature shows richer input validation.
+
+    FastMCP builds the input schema from the annotations (a Literal enum,
+    Field constraints, a custom validator and optional parameters with
+    defaults) and rejects invalid arguments before the tool runs.
+    """
+
+    @mcp.tool()
+    async def search_alerts(
+        state: Annotated[str, Field(pattern=r"^[A-Z]{2}$", description="Two-letter US state code (e.g. CA, NY)")],
+        severity: Annotated[
+            Literal["Extreme", "Severe", "Moderate", "Minor"] | None,
+            Field(description="Only return alerts of this severity"),
+        ] = None,
+        keyword: Annotated[
+            Annotated[str, AfterValidator(not_blank)] | None,
+            Field(description="Only return alerts whose event mentions this text"),
+        ] = None,
+        limit: Annotated[int, Field(ge=1, le=25, description="Maximum number of alerts to return")] = 5,
+    ) -> str:
+        """Search the active weather alerts for a US state."""
+        url = f"{NWS_API_BASE}/alerts/active/area/{state}"
+        data = await make_nws_request(url)
+
+        if not data or "features" not in data:
+            return "Unable to fetch alerts or no alerts found."
+
+        features = [
+            feature for feature in data["features"]
+            if (severity is None or feature["properties"].get("severity") == severity)
+            and (keyword is None or keyword.lower() in feature["properties"].get("event", "").lower())
+        ]
+        if not features:
+            return f"No active alerts for {state} match the filters."
+
+        return "\n---\n".join(format_alert(feature) for feature in features[:limit])
+"#;
+
+/// README entry for the input validation example tool
+const VALIDATION_README_ENTRY: &str = "- **search_alerts**: Search the active weather alerts for a US state, showing richer input validation\n  - Parameters: `state` (uppercase two-letter code), `severity` (optional enum), `keyword` (optional, at least 3 characters), `limit` (1-25, default 5)\n\n";
+
+/// Example tools built on one shared forecast helper
+const COMPOSITION_TOOLS: &str = r#"async def fetch_forecast_periods(latitude: float, longitude: float) -> list[dict[str, Any]] | None:
+    """Resolve the grid point and fetch its forecast periods.
+
+    Both tools below reuse this instead of each repeating the two NWS requests.
+    """
+    points_data = await make_nws_request(f"{NWS_API_BASE}/points/{latitude},{longitude}")
+    if not points_data:
+        return None
+
+    forecast_data = await make_nws_request(points_data["properties"]["forecast"])
+    if not forecast_data:
+        return None
+
+    return forecast_data["properties"]["periods"]
+
+
+def register_forecast_composition(mcp: FastMCP) -> None:
+    """Register two tools composed from fetch_forecast_periods."""
+
+    @mcp.tool()
+    async def get_forecast_summary(latitude: float, longitude: float, periods: int = 3) -> str:
+        """Get a one-line-per-period summary of the upcoming forecast for a location.
+
+        Args:
+            latitude: Latitude of the location
+            longitude: Longitude of the location
+            periods: Number of forecast periods to summarize
+        """
+        forecast = await fetch_forecast_periods(latitude, longitude)
+        if not forecast:
+            return "Unable to fetch forecast data for this location."
+
+        return "\n".join(
+            f"{period['name']}: {period['temperature']}°{period['temperatureUnit']}, {period['shortForecast']}"
+            for period in forecast[:periods]
+        )
+
+    @mcp.tool()
+    async def get_temperature_range(latitude: float, longitude: float) -> str:
+        """Get the lowest and highest forecast temperatures for a location.
+
+        Args:
+            latitude: Latitude of the location
+            longitude: Longitude of the location
+        """
+        forecast = await fetch_forecast_periods(latitude, longitude)
+        if not forecast:
+            return "Unable to fetch forecast data for this location."
+
+        temperatures = [period["temperature"] for period in forecast]
+        unit = forecast[0]["temperatureUnit"]
+        return f"Over the next {len(forecast)} periods temperatures range from {min(temperatures)}°{unit} to {max(temperatures)}°{unit}"
+"#;
+
+/// README entry for the tool composition example
+const COMPOSITION_README_ENTRY: &str = "- **get_forecast_summary**: Summarize the upcoming forecast periods for a location in one line each, built on the same helper as `get_temperature_range`\n  - Parameters: `latitude`, `longitude`, `periods` (default 3)\n\n- **get_temperature_range**: Get the lowest and highest forecast temperatures for a location\n  - Parameters: `latitude`, `longitude`\n\n";
+
+/// Sample static resource, registered alongside the tools
+const RESOURCES: &str = r#"def register_resources(mcp: FastMCP) -> None:
+    """Register a static resource that clients can read for context.
+
+    Unlike tools, which the model calls, resources are read by the client
+    (resources/read) and identified by their URI.
+    """
+
+    @mcp.resource(
+        "config://app",
+        name="app-config",
+        description="Configuration of this MCP server",
+        mime_type="application/json",
+    )
+    def app_config() -> str:
+        """Return the server configuration as JSON."""
+        return json.dumps({"name": "weather", "version": "1.0.0"}, indent=2)
+"#;
+
+/// README entry for the sample resource
+const RESOURCES_README_ENTRY: &str = "- **config://app** (resource): Configuration of this MCP server as JSON, read by clients via `resources/read` rather than called like a tool\n\n";
+
+/// Imports used by the pagination example
+const PAGINATION_IMPORTS: &str = "from mcp import types\nfrom mcp.shared.exceptions import McpError\n";
+
+/// Sample items listed a page at a time, registered after the other resources
+const PAGINATION_EXAMPLE: &str = r#"def register_pagination_example(mcp: FastMCP) -> None:
+    """Serve resources/list a page at a time, with sample items to page through.
+
+    FastMCP lists every resource at once, so this replaces its resources/list
+    handler. The cursor is opaque to clients; here it holds the offset of the
+    next page, returned as nextCursor until the last page.
+    """
+    page_size = 10
+
+    @mcp.resource(
+        "items://{item_id}",
+        name="sample-item",
+        description="One of the paginated sample items",
+        mime_type="text/plain",
+    )
+    def sample_item(item_id: str) -> str:
+        """Return the contents of a sample item."""
+        return f"Contents of {item_id}"
+
+    async def list_resources_page(request: types.ListResourcesRequest) -> types.ServerResult:
+        resources = await mcp.list_resources() + [
+            types.Resource(uri=f"items://item-{i}", name=f"item-{i}", mimeType="text/plain")
+            for i in range(1, 26)
+        ]
+        cursor = request.params.cursor if request.params else None
+        offset = int(cursor) if cursor and cursor.isdigit() else 0
+        if cursor is not None and (not cursor.isdigit() or offset > len(resources)):
+            raise McpError(types.ErrorData(code=types.INVALID_PARAMS, message=f"Invalid cursor: {cursor}"))
+
+        end = offset + page_size
+        next_cursor = str(end) if end < len(resources) else None
+        return types.ServerResult(types.ListResourcesResult(resources=resources[offset:end], nextCursor=next_cursor))
+
+    mcp._mcp_server.request_handlers[types.ListResourcesRequest] = list_resources_page
+"#;
+
+/// README entry for the pagination example
+const PAGINATION_README_ENTRY: &str = "- **items://{item_id}** (resource template): 25 sample items; `resources/list` returns 10 resources per page plus a `nextCursor` to pass back as `cursor` for the next page\n\n";
+
+/// Sample prompt template, registered alongside the tools
+const PROMPTS: &str = r#"def register_prompts(mcp: FastMCP) -> None:
+    """Register a prompt template that clients can offer to users.
+
+    Clients fetch prompts (prompts/get) with their arguments filled in and
+    send the resulting messages to the model.
+    """
+
+    @mcp.prompt(name="summarize", description="Summarize a piece of text")
+    def summarize(text: str) -> str:
+        """Ask the model to summarize the given text."""
+        return f"Please summarize the following text concisely:\n\n{text}"
+"#;
+
+/// README entry for the sample prompt
+const PROMPTS_README_ENTRY: &str = "- **summarize** (prompt): Template asking the model to summarize a piece of text, fetched by clients via `prompts/get`\n  - Arguments: `text`\n\n";
+
+/// Types of the completion handler's arguments and result
+const COMPLETION_IMPORTS: &str = "from mcp.types import (\n    Completion,\n    CompletionArgument,\n    CompletionContext,\n    PromptReference,\n    ResourceTemplateReference,\n)\n";
+
+/// Prompt whose `state` argument is completed from the US state codes
+const COMPLETION_EXAMPLE: &str = r#"# Two-letter codes suggested while the user types the `state` argument
+US_STATES = [
+    "AK", "AL", "AR", "AZ", "CA", "CO", "CT", "DC", "DE", "FL", "GA", "HI", "IA",
+    "ID", "IL", "IN", "KS", "KY", "LA", "MA", "MD", "ME", "MI", "MN", "MO", "MS",
+    "MT", "NC", "ND", "NE", "NH", "NJ", "NM", "NV", "NY", "OH", "OK", "OR", "PA",
+    "RI", "SC", "SD", "TN", "TX", "UT", "VA", "VT", "WA", "WI", "WV", "WY",
+]
+
+
+def register_completion_example(mcp: FastMCP) -> None:
+    """Register a prompt whose `state` argument clients can autocomplete.
+
+    Clients call completion/complete with what the user typed so far and
+    offer the returned values as suggestions.
+    """
+
+    @mcp.prompt(name="state-alerts", description="Ask about the active weather alerts of a US state")
+    def state_alerts(state: str) -> str:
+        """Ask the model about the active alerts of the given state."""
+        return f"What are the active weather alerts in {state}?"
+
+    @mcp.completion()
+    async def complete_argument(
+        ref: PromptReference | ResourceTemplateReference,
+        argument: CompletionArgument,
+        context: CompletionContext | None,
+    ) -> Completion | None:
+        """Suggest the state codes starting with the typed value."""
+        if isinstance(ref, PromptReference) and ref.name == "state-alerts" and argument.name == "state":
+            prefix = argument.value.upper()
+            return Completion(values=[code for code in US_STATES if code.startswith(prefix)])
+        return None
+"#;
+
+/// README entry for the prompt with an autocompleted argument
+const COMPLETION_README_ENTRY: &str = "- **state-alerts** (prompt): Asks about the active weather alerts of a US state, with the `state` argument autocompleted through `completion/complete`\n  - Arguments: `state`\n\n";
+
+/// Import of the exception FastMCP turns into an `isError` tool result
+const TOOL_ERROR_IMPORT: &str = "from mcp.server.fastmcp.exceptions import ToolError\n";
+
+/// Imports needed by the WebSocket transport
+const WS_IMPORTS: &str = r#"import uvicorn
+from mcp.server.websocket import websocket_server
+"#;
+
+/// ASGI app serving MCP sessions over WebSocket connections
+const WS_SERVER: &str = r#"# WebSocket transport
+HOST = "127.0.0.1"
+PORT = int(os.environ.get("PORT", "8080"))
+
+async def websocket_app(scope, receive, send):
+    """Serve one MCP session per WebSocket connection."""
+    if scope["type"] != "websocket":
+        return
+    async with websocket_server(scope, receive, send) as (read_stream, write_stream):
+        await mcp._mcp_server.run(
+            read_stream, write_stream, mcp._mcp_server.create_initialization_options()
+        )
+"#;
+
+/// Address FastMCP's SSE transport listens on
+const SSE_SERVER: &str = r#"# SSE transport
+HOST = "127.0.0.1"
+PORT = int(os.environ.get("PORT", "8080"))
+"#;
+
+/// Address FastMCP's Streamable HTTP transport listens on
+const HTTP_SERVER: &str = r#"# Streamable HTTP transport
+HOST = "127.0.0.1"
+PORT = int(os.environ.get("PORT", "3000"))
+"#;
+
+/// Imports needed by the OpenTelemetry setup
+const OTEL_IMPORTS: &str = r#"import functools
+import inspect
+from collections.abc import Callable
+from opentelemetry import trace
+from opentelemetry.exporter.otlp.proto.http.trace_exporter import OTLPSpanExporter
+from opentelemetry.sdk.resources import Resource
+from opentelemetry.sdk.trace import TracerProvider
+from opentelemetry.sdk.trace.export import BatchSpanProcessor
+"#;
+
+/// OpenTelemetry SDK setup wrapping every tool registered afterwards in a span
+const TELEMETRY: &str = r#"# OpenTelemetry tracing of tool calls, exported over OTLP/HTTP (configure the
+# collector with the standard OTEL_EXPORTER_OTLP_* environment variables)
+tracer_provider = TracerProvider(
+    resource=Resource.create({"service.name": os.environ.get("OTEL_SERVICE_NAME", "weather")})
+)
+tracer_provider.add_span_processor(BatchSpanProcessor(OTLPSpanExporter()))
+trace.set_tracer_provider(tracer_provider)
+tracer = trace.get_tracer("weather")
+register_tool = mcp.tool
+
+
+def traced_tool(*args: Any, **kwargs: Any) -> Callable[[Callable[..., Any]], Any]:
+    """Register a tool like mcp.tool, running each call in a span named after the tool."""
+    register = register_tool(*args, **kwargs)
+
+    def decorator(fn: Callable[..., Any]) -> Any:
+        name = kwargs.get("name") or fn.__name__
+
+        @functools.wraps(fn)
+        async def traced(*fn_args: Any, **fn_kwargs: Any) -> Any:
+            with tracer.start_as_current_span(f"tool {name}"):
+                result = fn(*fn_args, **fn_kwargs)
+                if inspect.isawaitable(result):
+                    result = await result
+                return result
+
+        return register(traced)
+
+    return decorator
+
+
+mcp.tool = traced_tool  # type: ignore[method-assign]
+"#;
+
+/// Example pytest suite for the weather tools
+const EXAMPLE_TEST: &str = r#""""Tests for the weather tools, run with pytest."""
+from typing import Any
+
+import pytest
+
+import server
+
+ALERT = {
+    "properties": {
+        "event": "Flood Warning",
+        "areaDesc": "Travis County",
+        "severity": "Severe",
+        "description": "Flooding is occurring.",
+        "instruction": "Move to higher ground.",
+    }
+}
+
+
+def test_format_alert() -> None:
+    formatted = server.format_alert(ALERT)
+
+    assert "Event: Flood Warning" in formatted
+    assert "Area: Travis County" in formatted
+    assert "Severity: Severe" in formatted
+    assert "Instructions: Move to higher ground." in formatted
+
+
+def test_format_alert_placeholders() -> None:
+    formatted = server.format_alert({"properties": {}})
+
+    assert "Event: Unknown" in formatted
+    assert "Instructions: No specific instructions provided" in formatted
+
+
+async def test_get_alerts(monkeypatch: pytest.MonkeyPatch) -> None:
+    requested: list[str] = []
+
+    async def fake_request(url: str) -> dict[str, Any] | None:
+        requested.append(url)
+        return {"features": [ALERT]}
+
+    # The tools look up make_nws_request in their own module, so patch it there
+    monkeypatch.setattr(server, "make_nws_request", fake_request)
+
+    result = await server.get_alerts("TX")
+
+    assert requested == [f"{server.NWS_API_BASE}/alerts/active/area/TX"]
+    assert result == server.format_alert(ALERT)
+"#;
@@ -0,0 +1,778 @@
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::Tool;
+use crate::utils::license;
+use super::{configure_git_identity, confirm_commands, http_readme_section, inspector_readme_section, print_dependency_table, project_files::ProjectFiles, server_name_from_dir, write_inspector_config, write_license, write_summary, Generator, GeneratorOptions, DEFAULT_WEATHER_API};
+
+/// Default entrypoint file name inside `src/`
+pub const DEFAULT_ENTRYPOINT: &str = "main.rs";
+
+/// Oldest Rust release the generated crate builds with: rmcp 0.3 uses edition 2024
+/// (1.85) and the current releases of reqwest's URL parsing dependencies need 1.88
+const RUST_VERSION: &str = "1.88";
+
+/// Crates written to Cargo.toml: name, version requirement and extra dependency keys
+const DEPENDENCIES: &[(&str, &str, &str)] = &[
+    ("anyhow", "1", ""),
+    ("reqwest", "0.12", r#"default-features = false, features = ["json", "rustls-tls"]"#),
+    ("rmcp", "0.3", r#"features = ["server", "transport-io", "macros"]"#),
+    ("serde", "1", r#"features = ["derive"]"#),
+    ("tokio", "1", r#"features = ["macros", "rt-multi-thread", "io-std"]"#),
+];
+
+pub struct RustGenerator {
+    project_name: String,
+    _tool: Tool,
+    project_path: PathBuf,
+    files: ProjectFiles,
+    options: GeneratorOptions,
+}
+
+impl Generator for RustGenerator {
+    fn new(project_name: &str, tool: &Tool, options: &GeneratorOptions) -> Self {
+        let project_path = options.project_path(project_name);
+        
+        Self {
+            project_name: project_name.to_string(),
+            _tool: tool.clone(),
+            files: ProjectFiles::new(project_path.clone(), options.dry_run, &options.line_endings),
+            project_path,
+            options: options.clone(),
+        }
+    }
+    
+    fn generate(&self) -> Result<()> {
+        // Create the project directory
+        self.create_directories()?;
+        
+        // Create project files
+        self.create_files()?;
+        
+        // Fetch the crate dependencies
+        if !self.options.no_install {
+            self.init_package_manager()?;
+        }
+        
+        // Initialize git
+        if !self.options.no_git {
+            self.init_git()?;
+        }
+        
+        if self.files.is_dry_run() {
+            self.files.print_plan();
+        }
+        
+        if self.options.print_tree_after {
+            self.files.print_tree();
+        }
+        
+        if self.options.dependency_table {
+            let dependencies: Vec<_> = DEPENDENCIES.iter().map(|(name, version, _)| (*name, *version, "runtime")).collect();
+            print_dependency_table("Cargo.toml", &dependencies);
+        }
+        
+        if let Some(path) = &self.options.summary_json {
+            write_summary(path, "rust", &["cargo", "rustc"], &self.files)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn create_directories(&self) -> Result<()> {
+        // Create main directory
+        self.files.create_root(self.options.allow_existing_empty)?;
+        
+        self.files.create_dir(&self.crate_path("src"))
+    }
+    
+    fn create_files(&self) -> Result<()> {
+        // Create Cargo.toml
+        self.create_cargo_toml()?;
+        
+        // Create .gitignore
+        self.create_gitignore()?;
+        
+        // Create LICENSE
+        write_license(&self.files, &self.options, &self.project_name)?;
+        
+        // Create .dockerignore
+        if self.options.with_dockerignore_only {
+            self.create_dockerignore()?;
+        }
+        
+        // Create main server file
+        self.create_server_file()?;
+        
+        // Create mcp-inspector.json
+        if self.options.with_inspector_config {
+            write_inspector_config(&self.files, self.options.server_name(), &self.launch_command())?;
+        }
+        
+        // Create README
+        self.create_readme()?;
+        
+        Ok(())
+    }
+    
+    fn init_package_manager(&self) -> Result<()> {
+        if self.options.frozen_lockfile {
+            return self.install_from_lockfile();
+        }
+        
+        let fetch_command = self.fetch_command(&[]);
+        
+        if self.files.is_dry_run() {
+            self.files.record_command(&fetch_command);
+            return Ok(());
+        }
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&fetch_command])? {
+            println!("⏭️  Skipping dependency download");
+            return Ok(());
+        }
+        
+        println!("📦 Fetching crate dependencies with cargo...");
+        let output = Command::new(&fetch_command[0])
+            .args(&fetch_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to run cargo fetch")?;
+        
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            eprintln!("⚠️ Warning: Failed to fetch dependencies: {}", error);
+            eprintln!("Please run '{}' manually in the project directory", shell_words::join(&fetch_command));
+        } else {
+            println!("✅ Dependencies fetched successfully");
+        }
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    fn init_git(&self) -> Result<()> {
+        if self.files.is_dry_run() {
+            self.files.record_command(&["git".to_string(), "init".to_string()]);
+            return configure_git_identity(&self.files, &self.project_path, &self.options);
+        }
+        
+        Command::new("git")
+            .args(["init"])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to initialize git repository")?;
+        
+        configure_git_identity(&self.files, &self.project_path, &self.options)
+    }
+}
+
+impl RustGenerator {
+    /// Command starting the server from the project directory
+    fn launch_command(&self) -> Vec<String> {
+        vec!["cargo".to_string(), "run".to_string(), "--quiet".to_string()]
+    }
+    
+    /// Entrypoint file name
+    fn entrypoint(&self) -> &str {
+        self.options.entrypoint_or(DEFAULT_ENTRYPOINT)
+    }
+    
+    /// Path of a file of the server crate, which is a workspace member with --cargo-workspace
+    fn crate_path(&self, relative: &str) -> String {
+        if self.options.cargo_workspace {
+            format!("{}/{}", self.package_name(), relative)
+        } else {
+            relative.to_string()
+        }
+    }
+    
+    /// Cargo package name, derived from the project directory
+    fn package_name(&self) -> String {
+        let name = server_name_from_dir(&self.project_name);
+        // Cargo rejects package names starting with a digit
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("mcp-{}", name)
+        } else {
+            name
+        }
+    }
+    
+    /// `cargo fetch` with the given flags and any extra install arguments
+    fn fetch_command(&self, flags: &[&str]) -> Vec<String> {
+        let mut command: Vec<String> = ["cargo", "fetch"]
+            .iter()
+            .chain(flags)
+            .map(|arg| arg.to_string())
+            .collect();
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Downloads exactly the crate versions Cargo.lock pins with `cargo fetch --locked`
+    fn install_from_lockfile(&self) -> Result<()> {
+        if !self.project_path.join("Cargo.lock").exists() {
+            bail!("--frozen-lockfile needs an existing Cargo.lock in the project");
+        }
+        
+        let fetch_command = self.fetch_command(&["--locked"]);
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&fetch_command])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
+        println!("📦 Fetching crates pinned by Cargo.lock...");
+        let output = Command::new(&fetch_command[0])
+            .args(&fetch_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to fetch dependencies with cargo fetch --locked")?;
+        
+        if !output.status.success() {
+            bail!(
+                "'{}' failed:\n{}",
+                shell_words::join(&fetch_command),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        println!("✅ Dependencies installed successfully");
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks that the rmcp crate resolves in the dependency graph
+    fn verify_sdk_resolves(&self) -> Result<()> {
+        println!("🔍 Verifying the MCP SDK resolves...");
+        
+        let output = Command::new("cargo")
+            .args(["tree", "--package", "rmcp", "--depth", "0"])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to run cargo tree to verify the MCP SDK")?;
+        
+        if !output.status.success() {
+            bail!(
+                "The MCP SDK does not resolve after installation:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        
+        println!("✅ MCP SDK resolves correctly");
+        Ok(())
+    }
+    
+    fn create_cargo_toml(&self) -> Result<()> {
+        let package_name = self.package_name();
+        // Cargo only finds src/main.rs by itself
+        let bin = if self.entrypoint() == DEFAULT_ENTRYPOINT {
+            String::new()
+        } else {
+            format!("\n[[bin]]\nname = \"{}\"\npath = \"src/{}\"\n", package_name, self.entrypoint())
+        };
+        
+        let dependencies = DEPENDENCIES
+            .iter()
+            .map(|(name, version, options)| match *options {
+                "" => format!("{} = \"{}\"", name, version),
+                options => format!("{} = {{ version = \"{}\", {} }}", name, version, options),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let license = license::spdx_id(&self.options.license)
+            .map(|id| format!("license = \"{}\"\n", id))
+            .unwrap_or_default();
+        
+        let cargo_toml = format!(r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+rust-version = "{}"
+description = "MCP (Model Context Protocol) Weather Server"
+{}{}
+[dependencies]
+{}
+"#, package_name, RUST_VERSION, license, bin, dependencies);
+        
+        self.files.write(&self.crate_path("Cargo.toml"), cargo_toml)?;
+        
+        if self.options.cargo_workspace {
+            // Edition 2021 is only the default resolver for packages, not virtual workspaces
+            let workspace_toml = format!(r#"[workspace]
+members = ["{}"]
+resolver = "2"
+"#, package_name);
+            self.files.write("Cargo.toml", workspace_toml)?;
+        }
+        
+        Ok(())
+    }
+    
+    fn create_gitignore(&self) -> Result<()> {
+        let gitignore = r#"# Build output
+/target/
+
+# Backup files generated by rustfmt
+**/*.rs.bk
+
+# Environment variables
+.env
+.env.*
+
+# IDE
+.idea/
+.vscode/
+*.swp
+*.swo
+.DS_Store
+
+# MCP specific
+*.log
+mcp_debug_output/
+claude_config_backup.json
+"#;
+        let gitignore = self.options.gitignore.as_deref().unwrap_or(gitignore);
+        
+        self.files.write(".gitignore", gitignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_dockerignore(&self) -> Result<()> {
+        let dockerignore = r#"# Build output is produced inside the image
+target/
+
+# Local environment and secrets
+.env
+.env.*
+
+# Version control and editors
+.git/
+.gitignore
+.idea/
+.vscode/
+.DS_Store
+"#;
+        
+        self.files.write(".dockerignore", dockerignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_server_file(&self) -> Result<()> {
+        let server_code = [
+            SERVER_IMPORTS.to_string(),
+            NWS_HELPERS.to_string(),
+            self.weather_tools(),
+            self.server_main(),
+        ].join("\n");
+        let server_code = server_code.replace(DEFAULT_WEATHER_API, self.options.weather_api());
+        
+        self.files.write(&self.crate_path(&format!("src/{}", self.entrypoint())), server_code)?;
+        
+        Ok(())
+    }
+    
+    /// Weather tools, reporting failures as isError results when requested
+    fn weather_tools(&self) -> String {
+        if self.options.tool_error_handling {
+            WEATHER_TOOLS.replace(
+                "CallToolResult::success(vec![Content::text(message)])",
+                "CallToolResult::error(vec![Content::text(message)])",
+            )
+        } else {
+            WEATHER_TOOLS.to_string()
+        }
+    }
+    
+    /// Server info and stdio entrypoint using the configured server name
+    fn server_main(&self) -> String {
+        SERVER_MAIN.replace(
+            "name: \"weather\".to_string(),",
+            &format!("name: {:?}.to_string(),", self.options.server_name()),
+        )
+    }
+    
+    fn create_readme(&self) -> Result<()> {
+        let package_name = self.package_name();
+        
+        let integration = if self.options.skip_readme_integration {
+            String::new()
+        } else {
+            format!(r#"For Claude for Desktop integration, you'll need to add the server to your Claude configuration. Open `~/Library/Application Support/Claude/claude_desktop_config.json` and add:
+
+```json
+{{
+  "mcpServers": {{
+    {:?}: {{
+      "command": "/ABSOLUTE/PATH/TO/{}/target/release/{}"
+    }}
+  }}
+}}
+```
+
+Replace `/ABSOLUTE/PATH/TO/{}` with the absolute path to your project.
+
+"#, self.options.server_name(), self.options.directory_name(&self.project_name), package_name, self.options.directory_name(&self.project_name))
+        };
+        
+        let integration = integration + &http_readme_section(&self.options) + &inspector_readme_section(&self.options);
+        
+        let layout = if self.options.cargo_workspace {
+            format!("### Project Layout\n\nThe project is a Cargo workspace whose `{}/` crate is the server. Add more crates to `members` in the top-level `Cargo.toml`; once several of them have binaries, pick the server with `cargo run --package {}`.\n\n", package_name, package_name)
+        } else {
+            String::new()
+        };
+        
+        let readme = format!(r#"# {}
+
+A Model Context Protocol (MCP) server implementation.
+
+## About
+
+This project implements an MCP server that provides weather information via the National Weather Service API. It is built with [rmcp](https://github.com/modelcontextprotocol/rust-sdk) and can be used with MCP compatible clients like Claude for Desktop.
+
+## Getting Started
+
+### Prerequisites
+
+- Rust {} or newer
+
+{}### Building the Server
+
+```bash
+cargo build --release
+```
+
+### Running the Server
+
+For development:
+```bash
+cargo run
+```
+
+{}## Available Tools
+
+This MCP server provides the following tools:
+
+- **get-alerts**: Get active weather alerts for a US state
+  - Parameters: `state` (two-letter state code)
+
+- **get-forecast**: Get weather forecast for a location
+  - Parameters: `latitude`, `longitude`
+
+## Example Queries for Claude
+
+After connecting your server to Claude for Desktop, you can ask questions like:
+
+- "What's the weather in Sacramento?"
+- "What are the active weather alerts in California?"
+- "Tell me the forecast for New York (40.7128, -74.0060)"
+{}"#,
+            self.project_name,
+            RUST_VERSION,
+            layout,
+            integration,
+            license::readme_section(&self.options.license)
+        );
+        
+        self.files.write("README.md", readme)?;
+        
+        Ok(())
+    }
+}
+
+/// Imports of the server file
+const SERVER_IMPORTS: &str = r#"use anyhow::Result;
+use rmcp::{
+    handler::server::{router::tool::ToolRouter, tool::Parameters},
+    model::{CallToolResult, Content, Implementation, ServerCapabilities, ServerInfo},
+    schemars, tool, tool_handler, tool_router,
+    transport::stdio,
+    ErrorData as McpError, ServerHandler, ServiceExt,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+// Needed by the code #[tool] generates under edition 2021
+use std::future::Future;
+"#;
+
+/// NWS API constants, response types and alert formatting
+const NWS_HELPERS: &str = r#"const NWS_API_BASE: &str = "https://api.weather.gov";
+const USER_AGENT: &str = "weather-app/1.0";
+
+#[derive(Debug, Deserialize)]
+struct AlertsResponse {
+    #[serde(default)]
+    features: Vec<AlertFeature>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AlertFeature {
+    properties: AlertProperties,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AlertProperties {
+    event: Option<String>,
+    area_desc: Option<String>,
+    severity: Option<String>,
+    description: Option<String>,
+    instruction: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PointsResponse {
+    properties: PointsProperties,
+}
+
+#[derive(Debug, Deserialize)]
+struct PointsProperties {
+    forecast: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastResponse {
+    properties: ForecastProperties,
+}
+
+#[derive(Debug, Deserialize)]
+struct ForecastProperties {
+    #[serde(default)]
+    periods: Vec<ForecastPeriod>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ForecastPeriod {
+    name: Option<String>,
+    temperature: Option<f64>,
+    temperature_unit: Option<String>,
+    wind_speed: Option<String>,
+    wind_direction: Option<String>,
+    detailed_forecast: Option<String>,
+}
+
+/// Formats an alert feature into a readable string
+fn format_alert(feature: &AlertFeature) -> String {
+    let props = &feature.properties;
+    let description: String = props
+        .description
+        .as_deref()
+        .unwrap_or("No description")
+        .chars()
+        .take(200)
+        .collect();
+    [
+        format!("Event: {}", props.event.as_deref().unwrap_or("Unknown")),
+        format!("Area: {}", props.area_desc.as_deref().unwrap_or("Unknown")),
+        format!("Severity: {}", props.severity.as_deref().unwrap_or("Unknown")),
+        format!("Description: {}...", description),
+        format!(
+            "Instructions: {}",
+            props.instruction.as_deref().unwrap_or("No specific instructions provided")
+        ),
+        "---".to_string(),
+    ]
+    .join("\n")
+}
+"#;
+
+/// Tool parameters, the server type and the weather tools
+const WEATHER_TOOLS: &str = r#"#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[schemars(crate = "rmcp::schemars")]
+struct AlertsRequest {
+    /// Two-letter state code (e.g. CA, NY)
+    state: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[schemars(crate = "rmcp::schemars")]
+struct ForecastRequest {
+    /// Latitude of the location
+    latitude: f64,
+    /// Longitude of the location
+    longitude: f64,
+}
+
+/// Reports a failed tool call to the client
+fn tool_failure(message: impl Into<String>) -> CallToolResult {
+    let message = message.into();
+    CallToolResult::success(vec![Content::text(message)])
+}
+
+#[derive(Clone)]
+struct Weather {
+    client: reqwest::Client,
+    tool_router: ToolRouter<Self>,
+}
+
+impl Weather {
+    fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            tool_router: Self::tool_router(),
+        }
+    }
+
+    /// Makes a request to the NWS API, returning None on failure
+    async fn make_nws_request<T: DeserializeOwned>(&self, url: &str) -> Option<T> {
+        let response = self
+            .client
+            .get(url)
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .header(reqwest::header::ACCEPT, "application/geo+json")
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+
+        match response {
+            Ok(response) => response
+                .json::<T>()
+                .await
+                .inspect_err(|error| eprintln!("Error parsing NWS response: {}", error))
+                .ok(),
+            Err(error) => {
+                eprintln!("Error making NWS request: {}", error);
+                None
+            }
+        }
+    }
+}
+
+#[tool_router]
+impl Weather {
+    #[tool(name = "get-alerts", description = "Get weather alerts for a state")]
+    async fn get_alerts(
+        &self,
+        Parameters(AlertsRequest { state }): Parameters<AlertsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        if state.chars().count() != 2 {
+            return Err(McpError::invalid_params("state must be a two-letter code", None));
+        }
+        let state_code = state.to_uppercase();
+        let alerts_url = format!("{}/alerts/active/area/{}", NWS_API_BASE, state_code);
+
+        let Some(alerts) = self.make_nws_request::<AlertsResponse>(&alerts_url).await else {
+            return Ok(tool_failure("Failed to retrieve alerts data"));
+        };
+
+        if alerts.features.is_empty() {
+            let text = format!("No active alerts for {}", state_code);
+            return Ok(CallToolResult::success(vec![Content::text(text)]));
+        }
+
+        let formatted_alerts: Vec<String> = alerts.features.iter().map(format_alert).collect();
+        let alerts_text = format!("Active alerts for {}:\n\n{}", state_code, formatted_alerts.join("\n"));
+
+        Ok(CallToolResult::success(vec![Content::text(alerts_text)]))
+    }
+
+    #[tool(name = "get-forecast", description = "Get weather forecast for a location")]
+    async fn get_forecast(
+        &self,
+        Parameters(ForecastRequest { latitude, longitude }): Parameters<ForecastRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err(McpError::invalid_params(
+                "latitude must be within [-90, 90] and longitude within [-180, 180]",
+                None,
+            ));
+        }
+
+        // Get grid point data
+        let points_url = format!("{}/points/{:.4},{:.4}", NWS_API_BASE, latitude, longitude);
+        let Some(points) = self.make_nws_request::<PointsResponse>(&points_url).await else {
+            return Ok(tool_failure(format!(
+                "Failed to retrieve grid point data for coordinates: {}, {}. This location may not be supported by the NWS API (only US locations are supported).",
+                latitude, longitude
+            )));
+        };
+
+        let Some(forecast_url) = points.properties.forecast else {
+            return Ok(tool_failure("Failed to get forecast URL from grid point data"));
+        };
+
+        // Get forecast data
+        let Some(forecast) = self.make_nws_request::<ForecastResponse>(&forecast_url).await else {
+            return Ok(tool_failure("Failed to retrieve forecast data"));
+        };
+
+        let periods = forecast.properties.periods;
+        if periods.is_empty() {
+            return Ok(tool_failure("No forecast periods available"));
+        }
+
+        // Format forecast periods
+        let formatted_forecast: Vec<String> = periods
+            .iter()
+            .take(5)
+            .map(|period| {
+                let temperature = period
+                    .temperature
+                    .map(|temperature| temperature.to_string())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                [
+                    format!("{}:", period.name.as_deref().unwrap_or("Unknown")),
+                    format!(
+                        "Temperature: {}°{}",
+                        temperature,
+                        period.temperature_unit.as_deref().unwrap_or("F")
+                    ),
+                    format!(
+                        "Wind: {} {}",
+                        period.wind_speed.as_deref().unwrap_or("Unknown"),
+                        period.wind_direction.as_deref().unwrap_or("")
+                    ),
+                    format!(
+                        "Forecast: {}",
+                        period.detailed_forecast.as_deref().unwrap_or("No forecast available")
+                    ),
+                    "---".to_string(),
+                ]
+                .join("\n")
+            })
+            .collect();
+        let forecast_text = format!(
+            "Forecast for {}, {}:\n\n{}",
+            latitude,
+            longitude,
+            formatted_forecast.join("\n")
+        );
+
+        Ok(CallToolResult::success(vec![Content::text(forecast_text)]))
+    }
+}
+"#;
+
+/// Server info and the stdio entrypoint
+const SERVER_MAIN: &str = r#"#[tool_handler]
+impl ServerHandler for Weather {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            server_info: Implementation {
+                name: "weather".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            ..Default::default()
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    eprintln!("Weather MCP Server running on stdio");
+    let service = Weather::new().serve(stdio()).await?;
+    service.waiting().await?;
+    Ok(())
+}
+"#;
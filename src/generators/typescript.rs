@@ -1,25 +1,35 @@
-use anyhow::{Context, Result};
-use std::fs;
+use anyhow::{bail, Context, Result};
 use std::path::PathBuf;
 use std::process::Command;
 
-use crate::Tool;
-use super::Generator;
+use crate::{Language, Template, Tool, Transport, TsModuleType, YarnLinker};
+use crate::utils::openapi::{OpenApiTools, ParamKind};
+use crate::utils::dependency_checker::MIN_NODE_VERSION;
+use crate::utils::license;
+use super::docker::{docker_readme_section, write_dockerfile};
+use super::{configure_git_identity, confirm_commands, http_readme_section, inspector_readme_section, print_dependency_table, project_files::ProjectFiles, strip_comments, write_ci_workflow, write_inspector_config, write_license, write_summary, Generator, GeneratorOptions, DEFAULT_HTTP_PORT, DEFAULT_PORT, DEFAULT_WEATHER_API};
+
+/// Default entrypoint file name inside `src/`
+pub const DEFAULT_ENTRYPOINT: &str = "index.ts";
 
 pub struct TypeScriptGenerator {
     project_name: String,
     tool: Tool,
     project_path: PathBuf,
+    files: ProjectFiles,
+    options: GeneratorOptions,
 }
 
 impl Generator for TypeScriptGenerator {
-    fn new(project_name: &str, tool: &Tool) -> Self {
-        let project_path = PathBuf::from(project_name);
+    fn new(project_name: &str, tool: &Tool, options: &GeneratorOptions) -> Self {
+        let project_path = options.project_path(project_name);
         
         Self {
             project_name: project_name.to_string(),
             tool: tool.clone(),
+            files: ProjectFiles::new(project_path.clone(), options.dry_run, &options.line_endings),
             project_path,
+            options: options.clone(),
         }
     }
     
@@ -31,52 +41,139 @@ impl Generator for TypeScriptGenerator {
         self.create_files()?;
         
         // Initialize package manager
-        self.init_package_manager()?;
+        if !self.options.no_install {
+            self.init_package_manager()?;
+        }
         
         // Initialize git
-        self.init_git()?;
+        if !self.options.no_git {
+            self.init_git()?;
+        }
+        
+        if self.files.is_dry_run() {
+            self.files.print_plan();
+        }
+        
+        if self.options.print_tree_after {
+            self.files.print_tree();
+        }
+        
+        if self.options.dependency_table {
+            let manifest = if self.is_deno() { "deno.json" } else { "package.json" };
+            let dependencies = self.dependencies().into_iter().map(|(name, version)| (name, version, "runtime"));
+            let dev_dependencies = self.dev_dependencies().into_iter().map(|(name, version)| (name, version, "dev"));
+            print_dependency_table(manifest, &dependencies.chain(dev_dependencies).collect::<Vec<_>>());
+        }
+        
+        if let Some(path) = &self.options.summary_json {
+            let package_manager = format!("{:?}", self.tool).to_lowercase();
+            write_summary(path, "typescript", &["node", &package_manager], &self.files)?;
+        }
         
         Ok(())
     }
     
     fn create_directories(&self) -> Result<()> {
         // Create main directory
-        fs::create_dir(&self.project_path)
-            .context(format!("Failed to create project directory: {}", self.project_path.display()))?;
+        self.files.create_root(self.options.allow_existing_empty)?;
         
         // Create subdirectories (simplified to match MCP examples)
-        let dirs = vec![
-            "src",
-            "build",
-        ];
+        let mut dirs = vec!["src"];
+        
+        // Deno runs the sources directly, so there is no build output
+        if !self.is_deno() {
+            dirs.push("build");
+        }
+        
+        if self.options.with_multi_file {
+            dirs.extend(["src/tools", "src/lib"]);
+            if self.options.with_resources {
+                dirs.push("src/resources");
+            }
+            if self.options.with_prompts {
+                dirs.push("src/prompts");
+            }
+        }
         
         for dir in dirs {
-            fs::create_dir_all(self.project_path.join(dir))
-                .context(format!("Failed to create directory: {}", dir))?;
+            self.files.create_dir(dir)?;
         }
         
         Ok(())
     }
     
     fn create_files(&self) -> Result<()> {
-        // Create package.json
-        self.create_package_json()?;
-        
-        // Create tsconfig.json
-        self.create_tsconfig_json()?;
+        if self.is_deno() {
+            // Create deno.json (tasks and npm import mappings)
+            self.create_deno_json()?;
+        } else {
+            // Create package.json
+            self.create_package_json()?;
+            
+            // Create tsconfig.json
+            self.create_tsconfig_json()?;
+        }
         
         // Create .gitignore
         self.create_gitignore()?;
         
+        // Create LICENSE
+        write_license(&self.files, &self.options, &self.project_name)?;
+        
+        // Create .yarnrc.yml so Yarn Berry uses the selected linker
+        if matches!(self.tool, Tool::Yarn) {
+            self.create_yarnrc()?;
+        }
+        
+        // Copy the user's .npmrc so authenticated installs work
+        if let Some(npmrc) = &self.options.npmrc {
+            self.files.copy(npmrc, ".npmrc")?;
+        }
+        
         // Create .prettierrc and .prettierignore
         self.create_prettier_config()?;
         
+        // Create vitest.config.ts and the example test
+        if self.options.with_tests {
+            self.create_tests()?;
+        }
+        
+        // Create eslint.config.js
+        if self.options.with_lint {
+            self.create_eslint_config()?;
+        }
+        
+        // Create .dockerignore
+        if self.options.with_dockerignore_only || self.options.docker {
+            self.create_dockerignore()?;
+        }
+        
+        // Create Dockerfile
+        if self.options.docker {
+            self.create_dockerfile()?;
+        }
+        
+        // Create the CI workflow
+        if self.options.ci.is_some() {
+            self.create_ci()?;
+        }
+        
         // Create main MCP server file
         self.create_server_file()?;
         
+        // Create mcp-inspector.json
+        if self.options.with_inspector_config {
+            write_inspector_config(&self.files, self.options.server_name(), &self.launch_command())?;
+        }
+        
         // Create README
         self.create_readme()?;
         
+        // Create QUICKSTART.md
+        if self.options.readme_quickstart_only {
+            self.create_quickstart()?;
+        }
+        
         Ok(())
     }
     
@@ -86,59 +183,57 @@ impl Generator for TypeScriptGenerator {
             Tool::Pnpm => "pnpm",
             Tool::Yarn => "yarn",
             Tool::Npm => "npm",
+            Tool::Bun => "bun",
+            Tool::Deno => "deno",
             _ => "npm",
         };
         
+        if self.options.frozen_lockfile {
+            return self.install_from_lockfile(cmd);
+        }
+        
+        if self.is_deno() {
+            return self.cache_deno_dependencies();
+        }
+        
+        let runtime_install = self.runtime_install_command(cmd);
+        let dev_install = self.dev_install_command(cmd);
+        
+        if self.files.is_dry_run() {
+            self.files.record_command(&runtime_install);
+            self.files.record_command(&dev_install);
+            return Ok(());
+        }
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&runtime_install, &dev_install])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
         println!("📦 Installing dependencies with {}...", cmd);
         
         // Install runtime dependencies
         println!("Installing runtime dependencies...");
-        let runtime_deps_result = match self.tool {
-            Tool::Yarn => {
-                Command::new(cmd)
-                    .args(["add", "@modelcontextprotocol/sdk", "zod"])
-                    .current_dir(&self.project_path)
-                    .output()
-            },
-            _ => {
-                Command::new(cmd)
-                    .args(["install", "@modelcontextprotocol/sdk", "zod"])
-                    .current_dir(&self.project_path)
-                    .output()
-            }
-        };
+        let runtime_deps_result = Command::new(&runtime_install[0])
+            .args(&runtime_install[1..])
+            .current_dir(&self.project_path)
+            .output();
         
         if let Err(e) = &runtime_deps_result {
             eprintln!("⚠️ Warning: Failed to install runtime dependencies: {}", e);
-            eprintln!("Please run '{} install @modelcontextprotocol/sdk zod' manually", cmd);
+            eprintln!("Please run '{}' manually", shell_words::join(&runtime_install));
         }
         
         // Install development dependencies
         println!("Installing development dependencies...");
-        let dev_deps_result = match self.tool {
-            Tool::Yarn => {
-                Command::new(cmd)
-                    .args(["add", "--dev", "@types/node", "typescript"])
-                    .current_dir(&self.project_path)
-                    .output()
-            },
-            Tool::Pnpm => {
-                Command::new(cmd)
-                    .args(["install", "-D", "@types/node", "typescript"])
-                    .current_dir(&self.project_path)
-                    .output()
-            },
-            _ => {
-                Command::new(cmd)
-                    .args(["install", "--save-dev", "@types/node", "typescript"])
-                    .current_dir(&self.project_path)
-                    .output()
-            }
-        };
+        let dev_deps_result = Command::new(&dev_install[0])
+            .args(&dev_install[1..])
+            .current_dir(&self.project_path)
+            .output();
         
         if let Err(e) = &dev_deps_result {
             eprintln!("⚠️ Warning: Failed to install development dependencies: {}", e);
-            eprintln!("Please run '{} install --save-dev @types/node typescript' manually", cmd);
+            eprintln!("Please run '{}' manually", shell_words::join(&dev_install));
         }
         
         if runtime_deps_result.is_ok() && dev_deps_result.is_ok() {
@@ -148,71 +243,418 @@ impl Generator for TypeScriptGenerator {
             eprintln!("Please check the output above and install any missing dependencies manually.");
         }
         
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
         Ok(())
     }
     
     fn init_git(&self) -> Result<()> {
+        if self.files.is_dry_run() {
+            self.files.record_command(&["git".to_string(), "init".to_string()]);
+            return configure_git_identity(&self.files, &self.project_path, &self.options);
+        }
+        
         Command::new("git")
             .args(["init"])
             .current_dir(&self.project_path)
             .output()
             .context("Failed to initialize git repository")?;
         
-        Ok(())
+        configure_git_identity(&self.files, &self.project_path, &self.options)
     }
 }
 
 impl TypeScriptGenerator {
+    /// Whether the project targets Deno instead of a Node.js package manager
+    fn is_deno(&self) -> bool {
+        matches!(self.tool, Tool::Deno)
+    }
+    
+    /// Whether the server starts from the minimal echo template instead of the weather example
+    fn is_minimal(&self) -> bool {
+        matches!(self.options.template, Template::Minimal)
+    }
+    
+    /// Command downloading the npm packages mapped in deno.json
+    fn deno_cache_command(&self) -> Vec<String> {
+        let mut command = vec!["deno".to_string(), "cache".to_string(), format!("src/{}", self.entrypoint())];
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Caches the entrypoint's dependencies with `deno cache`
+    fn cache_deno_dependencies(&self) -> Result<()> {
+        let cache_command = self.deno_cache_command();
+        
+        if self.files.is_dry_run() {
+            self.files.record_command(&cache_command);
+            return Ok(());
+        }
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&cache_command])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
+        println!("📦 Caching dependencies with deno...");
+        let output = Command::new(&cache_command[0])
+            .args(&cache_command[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context("Failed to run deno cache")?;
+        
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            eprintln!("⚠️ Warning: Failed to cache dependencies: {}", error);
+            eprintln!("Please run '{}' manually", shell_words::join(&cache_command));
+        } else {
+            println!("✅ Dependencies cached successfully");
+        }
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Command installing the runtime dependencies
+    fn runtime_install_command(&self, cmd: &str) -> Vec<String> {
+        let subcommand = match self.tool {
+            Tool::Yarn | Tool::Bun => "add",
+            _ => "install",
+        };
+        
+        let mut command: Vec<String> = [cmd, subcommand, "@modelcontextprotocol/sdk", "zod"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        match self.options.transport {
+            Transport::Stdio => {},
+            Transport::Ws => command.push("ws".to_string()),
+            Transport::Sse | Transport::Http => command.push("express".to_string()),
+        }
+        if self.options.with_otel {
+            command.extend(OTEL_PACKAGES.iter().map(|(package, _)| package.to_string()));
+        }
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Command installing the development dependencies
+    fn dev_install_command(&self, cmd: &str) -> Vec<String> {
+        let (subcommand, dev_flag) = match self.tool {
+            Tool::Yarn => ("add", "--dev"),
+            Tool::Pnpm => ("install", "-D"),
+            Tool::Bun => ("add", "-d"),
+            _ => ("install", "--save-dev"),
+        };
+        
+        let mut command: Vec<String> = [cmd, subcommand, dev_flag, "@types/node", "typescript"]
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect();
+        match self.options.transport {
+            Transport::Stdio => {},
+            Transport::Ws => command.push("@types/ws".to_string()),
+            Transport::Sse | Transport::Http => command.push("@types/express".to_string()),
+        }
+        if self.options.with_typescript_path_aliases {
+            command.extend(["tsc-alias".to_string(), "tsx".to_string()]);
+        }
+        if self.options.with_tests {
+            command.push("vitest".to_string());
+        }
+        if self.options.with_lint {
+            command.extend(["@eslint/js".to_string(), "eslint".to_string(), "typescript-eslint".to_string()]);
+        }
+        command.extend(self.options.extra_install_args.iter().cloned());
+        command
+    }
+    
+    /// Accepted lockfiles and the install command that fails instead of updating them
+    fn frozen_install_command(&self, cmd: &str) -> (&'static [&'static str], Vec<String>) {
+        let (lockfiles, args): (&[&str], &[&str]) = match self.tool {
+            Tool::Pnpm => (&["pnpm-lock.yaml"], &["install", "--frozen-lockfile"]),
+            Tool::Yarn => (&["yarn.lock"], &["install", "--immutable"]),
+            // Bun 1.2 writes a text bun.lock, older releases the binary bun.lockb
+            Tool::Bun => (&["bun.lock", "bun.lockb"], &["install", "--frozen-lockfile"]),
+            Tool::Deno => (&["deno.lock"], &["install", "--frozen"]),
+            _ => (&["package-lock.json"], &["ci"]),
+        };
+        
+        let mut command = vec![cmd.to_string()];
+        command.extend(args.iter().map(|arg| arg.to_string()));
+        command.extend(self.options.extra_install_args.iter().cloned());
+        (lockfiles, command)
+    }
+    
+    /// Installs exactly what the project's lockfile pins
+    fn install_from_lockfile(&self, cmd: &str) -> Result<()> {
+        let (lockfiles, install) = self.frozen_install_command(cmd);
+        let Some(lockfile) = lockfiles.iter().find(|file| self.project_path.join(file).exists()) else {
+            bail!("--frozen-lockfile needs an existing {} in the project", lockfiles.join(" or "));
+        };
+        
+        if self.options.confirm_install_commands && !confirm_commands(&[&install])? {
+            println!("⏭️  Skipping dependency installation");
+            return Ok(());
+        }
+        
+        println!("📦 Installing dependencies from {} with {}...", lockfile, cmd);
+        let output = Command::new(&install[0])
+            .args(&install[1..])
+            .current_dir(&self.project_path)
+            .output()
+            .context(format!("Failed to run '{}'", shell_words::join(&install)))?;
+        
+        if !output.status.success() {
+            bail!(
+                "'{}' failed:\n{}",
+                shell_words::join(&install),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        println!("✅ Dependencies installed successfully");
+        
+        if self.options.verify_sdk_resolves {
+            self.verify_sdk_resolves()?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Checks that the MCP SDK can actually be loaded from the installed node_modules
+    fn verify_sdk_resolves(&self) -> Result<()> {
+        println!("🔍 Verifying the MCP SDK can be imported...");
+        
+        let (runtime, eval_flag) = if self.is_deno() { ("deno", "eval") } else { ("node", "-e") };
+        let output = Command::new(runtime)
+            .args([
+                eval_flag,
+                "import('@modelcontextprotocol/sdk/server/mcp.js').catch((error) => { console.error(error.message); process.exit(1); })",
+            ])
+            .current_dir(&self.project_path)
+            .output()
+            .context(format!("Failed to run {} to verify the MCP SDK", runtime))?;
+        
+        if !output.status.success() {
+            bail!(
+                "The MCP SDK does not resolve after installation:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        
+        println!("✅ MCP SDK resolves correctly");
+        Ok(())
+    }
+    
+    /// Command starting the server from the project directory
+    fn launch_command(&self) -> Vec<String> {
+        let command = match self.tool {
+            Tool::Deno => format!("deno run {} src/{}", DENO_PERMISSIONS, self.entrypoint()),
+            Tool::Bun => format!("bun run src/{}", self.entrypoint()),
+            _ => format!("node {}", self.build_entrypoint()),
+        };
+        command.split(' ').map(|arg| arg.to_string()).collect()
+    }
+    
+    /// Entrypoint source file name inside `src/`
+    fn entrypoint(&self) -> &str {
+        self.options.entrypoint_or(DEFAULT_ENTRYPOINT)
+    }
+    
+    /// Compiled entrypoint path relative to the project root
+    fn build_entrypoint(&self) -> String {
+        let entrypoint = self.entrypoint();
+        format!("build/{}.js", entrypoint.strip_suffix(".ts").unwrap_or(entrypoint))
+    }
+    
+    /// File the server is started from: the compiled entrypoint, or the source under Deno
+    fn run_entrypoint(&self) -> String {
+        if self.is_deno() {
+            format!("src/{}", self.entrypoint())
+        } else {
+            self.build_entrypoint()
+        }
+    }
+    
+    /// Runtime dependencies and their version ranges
+    fn dependencies(&self) -> Vec<(&'static str, &'static str)> {
+        // The Streamable HTTP server transport first shipped in SDK 1.10
+        let sdk_version = if matches!(self.options.transport, Transport::Http) { "^1.10.0" } else { "^1.0.0" };
+        let mut dependencies = vec![(MCP_SDK_PACKAGE, sdk_version), ("zod", "^3.22.4")];
+        if self.options.with_otel {
+            dependencies.extend(OTEL_PACKAGES.iter().copied());
+        }
+        match self.options.transport {
+            Transport::Stdio => {},
+            Transport::Ws => dependencies.push(("ws", "^8.18.0")),
+            Transport::Sse | Transport::Http => dependencies.push(("express", "^4.21.2")),
+        }
+        dependencies
+    }
+    
+    /// Development dependencies and their version ranges (Deno projects have none)
+    fn dev_dependencies(&self) -> Vec<(&'static str, &'static str)> {
+        if self.is_deno() {
+            return Vec::new();
+        }
+        
+        let mut dependencies = Vec::new();
+        if self.options.with_lint {
+            dependencies.push(("@eslint/js", "^9.14.0"));
+        }
+        dependencies.push(("@types/node", "^20.10.0"));
+        match self.options.transport {
+            Transport::Stdio => {},
+            Transport::Ws => dependencies.push(("@types/ws", "^8.5.12")),
+            Transport::Sse | Transport::Http => dependencies.push(("@types/express", "^4.17.21")),
+        }
+        if self.options.with_lint {
+            dependencies.push(("eslint", "^9.14.0"));
+        }
+        dependencies.extend([("nodemon", "^3.0.2"), ("ts-node", "^10.9.2")]);
+        if self.options.with_typescript_path_aliases {
+            dependencies.extend([("tsc-alias", "^1.8.10"), ("tsx", "^4.19.0")]);
+        }
+        dependencies.push(("typescript", "^5.3.2"));
+        if self.options.with_lint {
+            dependencies.push(("typescript-eslint", "^8.14.0"));
+        }
+        if self.options.with_tests {
+            dependencies.push(("vitest", "^2.1.0"));
+        }
+        dependencies
+    }
+    
     fn create_package_json(&self) -> Result<()> {
-        let _package_manager = match self.tool {
+        let package_manager = match self.tool {
             Tool::Pnpm => "pnpm",
             Tool::Yarn => "yarn",
             Tool::Npm => "npm",
+            Tool::Bun => "bun",
+            Tool::Deno => "deno",
             _ => "npm", // Default fallback
         };
         
+        let entrypoint = self.entrypoint();
+        let build_entrypoint = self.build_entrypoint();
+        let (module_type, dev_script) = match self.options.ts_module_type {
+            TsModuleType::Esm => ("module", format!("nodemon --exec node --loader ts-node/esm src/{}", entrypoint)),
+            TsModuleType::Cjs => ("commonjs", format!("nodemon --exec ts-node src/{}", entrypoint)),
+        };
+        // tsx resolves tsconfig paths at dev time and tsc-alias rewrites them in the build output
+        let (dev_script, build_command) = if self.options.with_typescript_path_aliases {
+            (format!("tsx watch src/{}", entrypoint), "tsc && tsc-alias")
+        } else {
+            (dev_script, "tsc")
+        };
+        // Bun runs the TypeScript sources directly (and resolves tsconfig paths itself)
+        let (start_script, dev_script) = if package_manager == "bun" {
+            (format!("bun run src/{}", entrypoint), format!("bun --watch src/{}", entrypoint))
+        } else {
+            (format!("node {}", build_entrypoint), dev_script)
+        };
+        // Set the executable bit through Node, since `chmod` is not available on Windows
+        let chmod_command = format!("node -e \\\"require('fs').chmodSync('{}', '755')\\\"", build_entrypoint);
+        let author = self.options.author()
+            .map(|author| format!("\n  \"author\": {},", js_string(&author)))
+            .unwrap_or_default();
+        let license = license::spdx_id(&self.options.license)
+            .map(|id| format!("\n  \"license\": \"{}\",", id))
+            .unwrap_or_default();
+        // Command names cannot carry the scope of a scoped package name
+        let bin_name = self.project_name.rsplit('/').next().unwrap_or(&self.project_name);
+        let mut extra_scripts = String::new();
+        if self.options.with_tests {
+            extra_scripts.push_str(",\n    \"test\": \"vitest run\"");
+        }
+        if self.options.with_lint {
+            extra_scripts.push_str(",\n    \"lint\": \"eslint .\"");
+        }
+        
         let package_json = format!(
             r#"{{
   "name": "{}",
   "version": "0.1.0",
-  "description": "MCP (Model Context Protocol) server",
-  "type": "module",
-  "main": "build/index.js",
+  "description": "MCP (Model Context Protocol) server",{}{}
+  "type": "{}",
+  "main": "{}",
   "bin": {{
-    "{}": "./build/index.js"
+    "{}": "./{}"
   }},
   "scripts": {{
-    "start": "node build/index.js",
-    "dev": "nodemon --exec node --loader ts-node/esm src/index.ts",
-    "build": "tsc && chmod +x build/index.js"
+    "start": "{}",
+    "dev": "{}",
+    "build": "{} && {}"{}
   }},
   "dependencies": {{
-    "@modelcontextprotocol/sdk": "^1.0.0",
-    "zod": "^3.22.4"
+{}
   }},
   "devDependencies": {{
-    "@types/node": "^20.10.0",
-    "nodemon": "^3.0.2",
-    "ts-node": "^10.9.2",
-    "typescript": "^5.3.2"
+{}
   }},
   "engines": {{
-    "node": ">=16.0.0"
+    "node": ">=18.0.0"
   }}
 }}"#,
             self.project_name,
-            self.project_name
+            author,
+            license,
+            module_type,
+            build_entrypoint,
+            bin_name,
+            build_entrypoint,
+            start_script,
+            dev_script,
+            build_command,
+            chmod_command,
+            extra_scripts,
+            json_entries(&self.dependencies()),
+            json_entries(&self.dev_dependencies())
         );
         
-        fs::write(
-            self.project_path.join("package.json"),
-            package_json,
-        ).context("Failed to create package.json")?;
+        self.files.write("package.json", package_json)?;
+        
+        Ok(())
+    }
+    
+    fn create_deno_json(&self) -> Result<()> {
+        let run = format!("deno run {} src/{}", DENO_PERMISSIONS, self.entrypoint());
+        // The SDK is imported through subpaths, so it is mapped as a directory
+        let mut imports: Vec<(String, String)> = self.dependencies()
+            .into_iter()
+            .map(|(package, version)| match package {
+                MCP_SDK_PACKAGE => (format!("{}/", package), format!("npm:/{}@{}/", package, version)),
+                _ => (package.to_string(), format!("npm:{}@{}", package, version)),
+            })
+            .collect();
+        if self.options.with_typescript_path_aliases {
+            imports.push(("@/".to_string(), "./src/".to_string()));
+        }
+        
+        let deno_json = serde_json::json!({
+            "tasks": {
+                "start": run,
+                "dev": run.replacen("deno run", "deno run --watch", 1),
+            },
+            "imports": imports.into_iter()
+                .map(|(specifier, target)| (specifier, serde_json::Value::from(target)))
+                .collect::<serde_json::Map<_, _>>(),
+        });
+        let deno_json = serde_json::to_string_pretty(&deno_json)? + "\n";
+        
+        self.files.write("deno.json", deno_json)?;
         
         Ok(())
     }
     
     fn create_tsconfig_json(&self) -> Result<()> {
+        // Node16 emits ESM or CommonJS based on the package.json "type" field and is the
+        // only mode that honours the SDK's "exports" map, so both module types share it.
         let tsconfig_json = r#"{
   "compilerOptions": {
     "target": "ES2022",
@@ -228,11 +670,34 @@ impl TypeScriptGenerator {
   "include": ["src/**/*"],
   "exclude": ["node_modules"]
 }"#;
+        // Tests run through Vitest and stay out of the build output
+        let tsconfig_json = if self.options.with_tests {
+            tsconfig_json.replace("\"exclude\": [\"node_modules\"]", "\"exclude\": [\"node_modules\", \"src/**/*.test.ts\"]")
+        } else {
+            tsconfig_json.to_string()
+        };
+        let tsconfig_json = if self.options.with_typescript_path_aliases {
+            tsconfig_json.replace(
+                "\"forceConsistentCasingInFileNames\": true\n",
+                "\"forceConsistentCasingInFileNames\": true,\n    \"baseUrl\": \".\",\n    \"paths\": {\n      \"@/*\": [\"src/*\"]\n    }\n",
+            )
+        } else {
+            tsconfig_json.to_string()
+        };
+        
+        self.files.write("tsconfig.json", tsconfig_json)?;
+        
+        Ok(())
+    }
+    
+    /// Writes .yarnrc.yml selecting Yarn Berry's node linker (Yarn 1 ignores it)
+    fn create_yarnrc(&self) -> Result<()> {
+        let linker = match self.options.yarn_linker {
+            YarnLinker::Pnp => "pnp",
+            YarnLinker::NodeModules => "node-modules",
+        };
         
-        fs::write(
-            self.project_path.join("tsconfig.json"),
-            tsconfig_json,
-        ).context("Failed to create tsconfig.json")?;
+        self.files.write(".yarnrc.yml", format!("nodeLinker: {}\n", linker))?;
         
         Ok(())
     }
@@ -297,15 +762,131 @@ notes/
 .vercel
 .cache/
 "#;
+        let gitignore = self.options.gitignore.as_deref().unwrap_or(gitignore);
+        
+        // A copied .npmrc usually carries registry credentials
+        let gitignore = if self.options.npmrc.is_some() {
+            format!("{}\n# Registry credentials\n.npmrc\n", gitignore)
+        } else {
+            gitignore.to_string()
+        };
+        
+        self.files.write(".gitignore", gitignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_dockerignore(&self) -> Result<()> {
+        let dockerignore = r#"# Dependencies are installed inside the image
+node_modules/
+
+# Build output
+build/
+dist/
+*.tsbuildinfo
+
+# Logs
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+pnpm-debug.log*
+
+# Local environment and secrets
+.env
+.env.*
+.npmrc
+
+# Version control and editors
+.git/
+.gitignore
+.idea/
+.vscode/
+.DS_Store
+"#;
+        
+        self.files.write(".dockerignore", dockerignore)?;
+        
+        Ok(())
+    }
+    
+    fn create_dockerfile(&self) -> Result<()> {
+        let (package_manager, lockfiles, setup, prune) = match self.tool {
+            Tool::Pnpm => ("pnpm", "pnpm-lock.yaml*", "RUN corepack enable\n", "RUN pnpm prune --prod\n"),
+            Tool::Yarn => ("yarn", "yarn.lock* .yarnrc.yml*", "RUN corepack enable\n", "# Yarn has no prune command, so the dev dependencies stay in the image\n"),
+            _ => ("npm", "package-lock.json*", "", "RUN npm prune --omit=dev\n"),
+        };
+        // .dockerignore keeps the .npmrc out of the image; the install reads it as a build secret
+        let install = if self.options.npmrc.is_some() {
+            format!("RUN --mount=type=secret,id=npmrc,target=/app/.npmrc {} install", package_manager)
+        } else {
+            format!("RUN {} install", package_manager)
+        };
+        let build = format!(r#"# Build stage: install all dependencies and compile the TypeScript sources
+FROM node:22-slim AS build
+WORKDIR /app
+{setup}COPY package.json {lockfiles} ./
+{install}
+COPY . .
+RUN {package_manager} run build
+{prune}
+# Runtime stage: only the compiled server and its production dependencies
+FROM node:22-slim
+WORKDIR /app
+ENV NODE_ENV=production
+COPY --from=build /app/package.json ./
+COPY --from=build /app/node_modules ./node_modules
+COPY --from=build /app/build ./build
+"#);
         
-        fs::write(
-            self.project_path.join(".gitignore"),
-            gitignore,
-        ).context("Failed to create .gitignore")?;
+        write_dockerfile(&self.files, &self.options, &build, &["node".to_string(), self.build_entrypoint()])
+    }
+    
+    fn create_tests(&self) -> Result<()> {
+        self.files.write("vitest.config.ts", VITEST_CONFIG)?;
+        self.files.write("src/index.test.ts", EXAMPLE_TEST)?;
         
         Ok(())
     }
     
+    fn create_ci(&self) -> Result<()> {
+        let node = format!(r#"      - uses: actions/setup-node@v4
+        with:
+          node-version: "{}"
+"#, MIN_NODE_VERSION.0);
+        let mut steps = match self.tool {
+            Tool::Deno => format!(r#"      - uses: denoland/setup-deno@v2
+        with:
+          deno-version: v2.x
+      - run: deno install
+      - run: deno check src/{}
+"#, self.entrypoint()),
+            Tool::Bun => format!(r#"{}      - uses: oven-sh/setup-bun@v2
+      - run: bun install
+      - run: bun run build
+"#, node),
+            // pnpm and Yarn ship with Node.js through corepack
+            Tool::Pnpm | Tool::Yarn => {
+                let package_manager = if matches!(self.tool, Tool::Pnpm) { "pnpm" } else { "yarn" };
+                format!(r#"{}      - run: corepack enable
+      - run: {pm} install
+      - run: {pm} run build
+"#, node, pm = package_manager)
+            },
+            _ => format!(r#"{}      - run: npm install
+      - run: npm run build
+"#, node),
+        };
+        let package_manager = format!("{:?}", self.tool).to_lowercase();
+        if self.options.with_lint {
+            steps.push_str(&format!("      - run: {} run lint\n", package_manager));
+        }
+        if self.options.with_tests {
+            steps.push_str(&format!("      - run: {} run test\n", package_manager));
+        }
+        
+        write_ci_workflow(&self.files, &self.options, &steps)
+    }
+    
     fn create_prettier_config(&self) -> Result<()> {
         // Create .prettierrc
         let prettierrc = r#"{
@@ -316,10 +897,7 @@ notes/
   "tabWidth": 2
 }"#;
         
-        fs::write(
-            self.project_path.join(".prettierrc"),
-            prettierrc,
-        ).context("Failed to create .prettierrc")?;
+        self.files.write(".prettierrc", prettierrc)?;
         
         // Create .prettierignore
         let prettierignore = r#"node_modules/
@@ -329,51 +907,788 @@ coverage/
 .next/
 "#;
         
-        fs::write(
-            self.project_path.join(".prettierignore"),
-            prettierignore,
-        ).context("Failed to create .prettierignore")?;
+        self.files.write(".prettierignore", prettierignore)?;
         
         Ok(())
     }
     
-    fn create_server_file(&self) -> Result<()> {
-        let server_code = r#"#!/usr/bin/env node
-import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
-import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
-import { z } from "zod";
-
-const NWS_API_BASE = "https://api.weather.gov";
-const USER_AGENT = "weather-app/1.0";
-
-// Create server instance
-const server = new McpServer({
-  name: "weather",
-  version: "1.0.0",
-});
-
-// Helper function for making NWS API requests
-async function makeNWSRequest<T>(url: string): Promise<T | null> {
-  const headers = {
-    "User-Agent": USER_AGENT,
-    Accept: "application/geo+json",
-  };
-
-  try {
-    const response = await fetch(url, { headers });
-    if (!response.ok) {
-      throw new Error(`HTTP error! status: ${response.status}`);
+    /// Writes the ESLint flat config, as an `.mjs` file in CommonJS packages since it uses imports
+    fn create_eslint_config(&self) -> Result<()> {
+        // Files outside tsconfig.json's `include` are linted without type information
+        let mut untyped_files = vec!["\"**/*.js\"", "\"**/*.mjs\""];
+        if self.options.with_tests {
+            untyped_files.extend(["\"**/*.test.ts\"", "\"vitest.config.ts\""]);
+        }
+        let config = ESLINT_CONFIG.replace("\"**/*.js\"", &untyped_files.join(", "));
+        
+        self.files.write(self.eslint_config_file(), config)?;
+        
+        Ok(())
     }
-    return (await response.json()) as T;
-  } catch (error) {
-    console.error("Error making NWS request:", error);
-    return null;
-  }
-}
-
-interface AlertFeature {
-  properties: {
-    event?: string;
+    
+    /// ESLint config file name
+    fn eslint_config_file(&self) -> &'static str {
+        match self.options.ts_module_type {
+            TsModuleType::Esm => "eslint.config.js",
+            TsModuleType::Cjs => "eslint.config.mjs",
+        }
+    }
+    
+    fn create_server_file(&self) -> Result<()> {
+        if self.options.with_multi_file {
+            return self.create_multi_file_server();
+        }
+        
+        let mut sections = if self.is_minimal() {
+            vec![
+                self.transport_imports(SERVER_IMPORTS),
+                self.server_instance(),
+                ECHO_TOOL.to_string(),
+            ]
+        } else {
+            vec![
+                self.transport_imports(SERVER_IMPORTS),
+                NWS_CONSTANTS.to_string(),
+                self.server_instance(),
+                self.nws_helpers(),
+                format!("// Register weather tools\n{}", self.weather_tools()),
+            ]
+        };
+        if self.options.with_otel {
+            // Tracing wraps server.tool, so it goes right after the server is created
+            let server_index = if self.is_minimal() { 2 } else { 3 };
+            sections[0].push_str(OTEL_IMPORTS);
+            sections.insert(server_index, self.telemetry());
+        }
+        if let Some(openapi) = &self.options.openapi_tools {
+            sections.push(format!(
+                "// Tools generated from the OpenAPI spec\n{}\n{}",
+                openapi_constants(openapi),
+                openapi_tool_registrations(openapi)
+            ));
+        }
+        if self.options.with_graceful_degradation {
+            sections.push(format!("{}\nregisterConditionsTool(server);\n", self.conditions_tool()));
+        }
+        if self.options.with_input_validation_examples {
+            sections.push(format!("{}\nregisterValidationExamples(server);\n", self.validation_tool()));
+        }
+        if self.options.with_multi_tool_example {
+            sections.push(format!("{}\nregisterForecastComposition(server);\n", self.composition_tools()));
+        }
+        if self.options.with_resources {
+            sections.push(format!("{}\nregisterResources(server);\n", self.resources()));
+        }
+        if self.options.with_pagination_example {
+            sections[0] = sections[0].replace("import { McpServer } from", "import { McpServer, ResourceTemplate } from") + PAGINATION_IMPORTS;
+            sections.push(format!("{}\nregisterPaginationExample(server);\n", PAGINATION_EXAMPLE));
+        }
+        if self.options.with_prompts {
+            sections.push(format!("{}\nregisterPrompts(server);\n", PROMPTS));
+        }
+        if self.options.with_completion_example {
+            sections[0].push_str(COMPLETION_IMPORTS);
+            sections.push(format!("{}\nregisterCompletionExample(server);\n", COMPLETION_EXAMPLE));
+        }
+        sections.push(self.server_main());
+        let server_code = sections.join("\n");
+        let server_code = server_code.replace(DEFAULT_WEATHER_API, self.options.weather_api());
+        let server_code = self.validate_nws_responses(server_code);
+        
+        let entrypoint = format!("src/{}", self.entrypoint());
+        self.files.write(&entrypoint, self.finish_source(server_code))?;
+        
+        Ok(())
+    }
+    
+    /// NWS request helper and response types, validated with zod schemas when requested
+    fn nws_helpers(&self) -> String {
+        if self.options.with_graceful_json_parse {
+            [NWS_SCHEMAS, NWS_REQUEST_VALIDATED, ALERT_FORMATTER].join("\n")
+        } else {
+            [NWS_REQUEST, ALERT_FEATURE, ALERT_FORMATTER, NWS_RESPONSE_TYPES].join("\n")
+        }
+    }
+    
+    /// Passes the response schemas to the NWS requests of the tools when responses are validated
+    fn validate_nws_responses(&self, code: String) -> String {
+        if !self.options.with_graceful_json_parse {
+            return code;
+        }
+        
+        VALIDATED_NWS_CALLS.iter().fold(code, |code, (call, validated)| code.replace(call, validated))
+    }
+    
+    /// Generated source as written, without its comments when requested
+    fn finish_source(&self, code: String) -> String {
+        if self.options.strip_comments {
+            strip_comments(&code, &Language::Typescript)
+        } else {
+            code
+        }
+    }
+    
+    /// README section on running the example tests, if any
+    fn tests_readme_section(&self, package_manager: &str) -> String {
+        if !self.options.with_tests {
+            return String::new();
+        }
+        
+        format!("### Running the Tests\n\n```bash\n# Run the Vitest suite (src/**/*.test.ts)\n{} run test\n```\n\n", package_manager)
+    }
+    
+    /// README section on linting the sources, if ESLint is set up
+    fn lint_readme_section(&self, package_manager: &str) -> String {
+        if !self.options.with_lint {
+            return String::new();
+        }
+        
+        format!("### Linting\n\n```bash\n# Check the sources with ESLint ({})\n{} run lint\n```\n\n", self.eslint_config_file(), package_manager)
+    }
+    
+    /// Weather tool registrations, flagging failures with `isError` when requested
+    fn weather_tools(&self) -> String {
+        if self.options.tool_error_handling {
+            flag_tool_errors(WEATHER_TOOLS)
+        } else {
+            WEATHER_TOOLS.to_string()
+        }
+    }
+    
+    /// Optional tool registered only when its API key is set, flagging failures when requested
+    fn conditions_tool(&self) -> String {
+        if self.options.tool_error_handling {
+            flag_tool_errors(CONDITIONS_TOOL)
+        } else {
+            CONDITIONS_TOOL.to_string()
+        }
+    }
+    
+    /// Example tool with a richer input schema, flagging failures when requested
+    fn validation_tool(&self) -> String {
+        if self.options.tool_error_handling {
+            flag_tool_errors(VALIDATION_TOOL)
+        } else {
+            VALIDATION_TOOL.to_string()
+        }
+    }
+    
+    /// Example tools sharing a forecast helper, flagging failures when requested
+    fn composition_tools(&self) -> String {
+        if self.options.tool_error_handling {
+            flag_tool_errors(COMPOSITION_TOOLS)
+        } else {
+            COMPOSITION_TOOLS.to_string()
+        }
+    }
+    
+    /// Sample resource describing the server, named after it
+    fn resources(&self) -> String {
+        RESOURCES.replace("name: \"weather\"", &format!("name: {}", js_string(self.options.server_name())))
+    }
+    
+    /// Server construction using the configured server name, declaring its capabilities when requested
+    fn server_instance(&self) -> String {
+        let instance = SERVER_INSTANCE.replace("name: \"weather\"", &format!("name: {}", js_string(self.options.server_name())));
+        if !self.options.server_capabilities {
+            return instance;
+        }
+        
+        let capabilities: String = self.options.capabilities().iter()
+            .map(|capability| format!("    {}: {{}},\n", capability))
+            .collect();
+        instance.replace("\n});\n", &format!("\n}}, {{\n  capabilities: {{\n{}  }},\n}});\n", capabilities))
+    }
+    
+    /// OpenTelemetry setup wrapping tool registrations, named after the server
+    fn telemetry(&self) -> String {
+        TELEMETRY.replace("\"weather\"", &js_string(self.options.server_name()))
+    }
+    
+    /// Swaps the stdio transport import for the selected transport's imports
+    fn transport_imports(&self, imports: &str) -> String {
+        match self.options.transport {
+            Transport::Stdio => imports.to_string(),
+            Transport::Ws => imports.replace(STDIO_IMPORT, WS_IMPORTS),
+            Transport::Sse => imports.replace(STDIO_IMPORT, SSE_IMPORTS),
+            Transport::Http => imports.replace(STDIO_IMPORT, HTTP_IMPORTS),
+        }
+    }
+    
+    /// Code connecting the server to the selected transport
+    fn server_main(&self) -> String {
+        let main = match self.options.transport {
+            Transport::Stdio if self.is_minimal() => return STDIO_MAIN.replace("Weather MCP Server", "MCP Server"),
+            Transport::Stdio => return STDIO_MAIN.to_string(),
+            Transport::Ws => WS_MAIN.replace(&DEFAULT_PORT.to_string(), &self.options.port().to_string()),
+            Transport::Sse => SSE_MAIN.replace(&DEFAULT_PORT.to_string(), &self.options.port().to_string()),
+            Transport::Http => HTTP_MAIN.replace(&DEFAULT_HTTP_PORT.to_string(), &self.options.port().to_string()),
+        };
+        if self.options.docker {
+            // The container sets HOST so the server listens on all interfaces
+            main.replace("://127.0.0.1:", "://${HOST}:")
+                .replace("\"127.0.0.1\"", "HOST")
+                .replacen("const PORT", "const HOST = process.env.HOST ?? \"127.0.0.1\";\nconst PORT", 1)
+        } else {
+            main
+        }
+    }
+    
+    /// Splits the server into a bootstrap entrypoint, tool registrations and API helpers
+    fn create_multi_file_server(&self) -> Result<()> {
+        let nws_code = [
+            export_declarations(NWS_CONSTANTS),
+            export_declarations(&self.nws_helpers()),
+        ].join("\n");
+        let nws_code = if self.options.with_graceful_json_parse {
+            format!("import {{ z }} from \"zod\";\n\n{}", nws_code)
+        } else {
+            nws_code
+        };
+        let nws_code = nws_code.replace(DEFAULT_WEATHER_API, self.options.weather_api());
+        
+        let tools_code = format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+import {{ z }} from "zod";
+import {{
+  NWS_API_BASE,
+  formatAlert,
+  makeNWSRequest,
+  type AlertsResponse,
+  type ForecastPeriod,
+  type ForecastResponse,
+  type PointsResponse,
+}} from "../lib/nws.js";
+
+// Register weather tools on the given server
+export function registerWeatherTools(server: McpServer): void {{
+{}}}
+"#, indent(&self.weather_tools(), 2));
+        
+        let mut imports = String::from(r#"#!/usr/bin/env node
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
+import { registerWeatherTools } from "./tools/weather.js";
+"#);
+        let mut registrations = String::from("// Register weather tools\nregisterWeatherTools(server);\n");
+        let mut files = vec![
+            ("src/lib/nws.ts".to_string(), nws_code),
+            ("src/tools/weather.ts".to_string(), tools_code),
+        ];
+        
+        if let Some(openapi) = &self.options.openapi_tools {
+            imports.push_str("import { registerOpenApiTools } from \"./tools/openapi.js\";\n");
+            registrations.push_str("registerOpenApiTools(server);\n");
+            files.push(("src/tools/openapi.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+import {{ z }} from "zod";
+
+{}
+// Register tools generated from the OpenAPI spec on the given server
+export function registerOpenApiTools(server: McpServer): void {{
+{}}}
+"#, openapi_constants(openapi), indent(&openapi_tool_registrations(openapi), 2))));
+        }
+        
+        if self.options.with_graceful_degradation {
+            imports.push_str("import { registerConditionsTool } from \"./tools/conditions.js\";\n");
+            registrations.push_str("registerConditionsTool(server);\n");
+            files.push(("src/tools/conditions.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+import {{ z }} from "zod";
+import {{ makeNWSRequest }} from "../lib/nws.js";
+
+{}"#, export_declarations(&self.conditions_tool()))));
+        }
+        
+        if self.options.with_input_validation_examples {
+            imports.push_str("import { registerValidationExamples } from \"./tools/validation.js\";\n");
+            registrations.push_str("registerValidationExamples(server);\n");
+            files.push(("src/tools/validation.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+import {{ z }} from "zod";
+import {{ NWS_API_BASE, formatAlert, makeNWSRequest, type AlertsResponse }} from "../lib/nws.js";
+
+{}"#, export_declarations(&self.validation_tool()))));
+        }
+        
+        if self.options.with_multi_tool_example {
+            imports.push_str("import { registerForecastComposition } from \"./tools/composition.js\";\n");
+            registrations.push_str("registerForecastComposition(server);\n");
+            files.push(("src/tools/composition.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+import {{ z }} from "zod";
+import {{
+  NWS_API_BASE,
+  makeNWSRequest,
+  type ForecastPeriod,
+  type ForecastResponse,
+  type PointsResponse,
+}} from "../lib/nws.js";
+
+{}"#, export_declarations(&self.composition_tools()))));
+        }
+        
+        if self.options.with_resources {
+            imports.push_str("import { registerResources } from \"./resources/config.js\";\n");
+            registrations.push_str("registerResources(server);\n");
+            files.push(("src/resources/config.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+
+{}"#, export_declarations(&self.resources()))));
+        }
+        
+        if self.options.with_pagination_example {
+            imports.push_str("import { registerPaginationExample } from \"./resources/pagination.js\";\n");
+            registrations.push_str("registerPaginationExample(server);\n");
+            files.push(("src/resources/pagination.ts".to_string(), format!(r#"import {{ McpServer, ResourceTemplate }} from "@modelcontextprotocol/sdk/server/mcp.js";
+{}
+{}"#, PAGINATION_IMPORTS, export_declarations(PAGINATION_EXAMPLE))));
+        }
+        
+        if self.options.with_prompts {
+            imports.push_str("import { registerPrompts } from \"./prompts/summarize.js\";\n");
+            registrations.push_str("registerPrompts(server);\n");
+            files.push(("src/prompts/summarize.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+import {{ z }} from "zod";
+
+{}"#, export_declarations(PROMPTS))));
+        }
+        
+        if self.options.with_completion_example {
+            imports.push_str("import { registerCompletionExample } from \"./prompts/completion.js\";\n");
+            registrations.push_str("registerCompletionExample(server);\n");
+            files.push(("src/prompts/completion.ts".to_string(), format!(r#"import {{ McpServer }} from "@modelcontextprotocol/sdk/server/mcp.js";
+{}import {{ z }} from "zod";
+
+{}"#, COMPLETION_IMPORTS, export_declarations(COMPLETION_EXAMPLE))));
+        }
+        
+        let mut imports = self.transport_imports(&imports);
+        let mut server_instance = self.server_instance();
+        if self.options.with_otel {
+            imports.push_str(OTEL_IMPORTS);
+            server_instance = format!("{}\n{}", server_instance, self.telemetry());
+        }
+        let server_main = self.server_main();
+        let index_code = [
+            imports.as_str(),
+            server_instance.as_str(),
+            registrations.as_str(),
+            server_main.as_str(),
+        ].join("\n");
+        files.push((format!("src/{}", self.entrypoint()), index_code));
+        
+        for (path, content) in files {
+            let content = if self.options.with_typescript_path_aliases {
+                content.replace("from \"../lib/", "from \"@/lib/").replace("from \"./tools/", "from \"@/tools/").replace("from \"./resources/", "from \"@/resources/").replace("from \"./prompts/", "from \"@/prompts/")
+            } else {
+                content
+            };
+            let content = if self.is_deno() {
+                deno_local_imports(&content)
+            } else {
+                content
+            };
+            let content = self.validate_nws_responses(content);
+            self.files.write(&path, self.finish_source(content))?;
+        }
+        
+        Ok(())
+    }
+    
+    fn create_readme(&self) -> Result<()> {
+        let package_manager = match self.tool {
+            Tool::Pnpm => "pnpm",
+            Tool::Yarn => "yarn",
+            Tool::Npm => "npm",
+            Tool::Bun => "bun",
+            Tool::Deno => "deno",
+            _ => "npm",
+        };
+        
+        let mut extra_tools = String::new();
+        if self.options.with_graceful_degradation {
+            extra_tools.push_str(CONDITIONS_README_ENTRY);
+        }
+        if self.options.with_input_validation_examples {
+            extra_tools.push_str(VALIDATION_README_ENTRY);
+        }
+        if self.options.with_multi_tool_example {
+            extra_tools.push_str(COMPOSITION_README_ENTRY);
+        }
+        if self.options.with_resources {
+            extra_tools.push_str(RESOURCES_README_ENTRY);
+        }
+        if self.options.with_pagination_example {
+            extra_tools.push_str(PAGINATION_README_ENTRY);
+        }
+        if self.options.with_prompts {
+            extra_tools.push_str(PROMPTS_README_ENTRY);
+        }
+        if self.options.with_completion_example {
+            extra_tools.push_str(COMPLETION_README_ENTRY);
+        }
+        if let Some(openapi) = &self.options.openapi_tools {
+            extra_tools.push_str(&openapi.readme_entries());
+        }
+        
+        let server_path = format!("/ABSOLUTE/PATH/TO/{}/{}", self.options.directory_name(&self.project_name), self.run_entrypoint());
+        let (command, args) = if self.is_deno() {
+            let mut args: Vec<String> = format!("run {}", DENO_PERMISSIONS).split(' ').map(js_string).collect();
+            args.push(js_string(&server_path));
+            ("deno", args)
+        } else {
+            ("node", vec![js_string(&server_path)])
+        };
+        
+        let integration = if self.options.skip_readme_integration {
+            String::new()
+        } else {
+            format!(r#"For Claude for Desktop integration, you'll need to add the server to your Claude configuration. Open `~/Library/Application Support/Claude/claude_desktop_config.json` and add:
+
+```json
+{{
+  "mcpServers": {{
+    {}: {{
+      "command": "{}",
+      "args": [
+        {}
+      ]
+    }}
+  }}
+}}
+```
+
+Replace `/ABSOLUTE/PATH/TO/{}` with the absolute path to your project.
+
+"#, js_string(self.options.server_name()), command, args.join(",\n        "), self.options.directory_name(&self.project_name))
+        };
+        
+        let (prerequisites, build_section, dev_command) = if self.is_deno() {
+            ("- Deno 2 or newer".to_string(), String::new(), "deno task dev".to_string())
+        } else {
+            (
+                format!("- Node.js {} or newer\n- {} (package manager)", MIN_NODE_VERSION.0, package_manager),
+                format!("### Building the Server\n\n```bash\n# Build the TypeScript code\n{} run build\n```\n\n{}{}", package_manager, self.tests_readme_section(package_manager), self.lint_readme_section(package_manager)),
+                format!("{} run dev", package_manager),
+            )
+        };
+        
+        let integration = integration + &http_readme_section(&self.options) + &inspector_readme_section(&self.options)
+            + &docker_readme_section(&self.options, &self.project_name);
+        let (about, tools, examples) = if self.is_minimal() {
+            (MINIMAL_README_ABOUT, MINIMAL_README_TOOLS, MINIMAL_README_EXAMPLES)
+        } else {
+            (WEATHER_README_ABOUT, WEATHER_README_TOOLS, WEATHER_README_EXAMPLES)
+        };
+        
+        let readme = format!(r#"# {}
+
+A Model Context Protocol (MCP) server implementation.
+
+## About
+
+{}
+
+## Getting Started
+
+### Prerequisites
+
+{}
+
+### Installation
+
+```bash
+# Install dependencies
+{} install
+```
+
+{}### Running the Server
+
+For development:
+```bash
+# Run in development mode with hot reload
+{}
+```
+
+{}## Available Tools
+
+This MCP server provides the following tools:
+
+{}{}## Example Queries for Claude
+
+After connecting your server to Claude for Desktop, you can ask questions like:
+
+{}{}"#,
+            self.project_name,
+            about,
+            prerequisites,
+            package_manager,
+            build_section,
+            dev_command,
+            integration,
+            tools,
+            extra_tools,
+            examples,
+            license::readme_section(&self.options.license)
+        );
+        
+        self.files.write("README.md", readme)?;
+        
+        Ok(())
+    }
+    
+    /// Writes QUICKSTART.md with just the commands to install, build and run the server
+    fn create_quickstart(&self) -> Result<()> {
+        let package_manager = match self.tool {
+            Tool::Pnpm => "pnpm",
+            Tool::Yarn => "yarn",
+            Tool::Npm => "npm",
+            Tool::Bun => "bun",
+            Tool::Deno => "deno",
+            _ => "npm",
+        };
+        
+        let run_commands = if self.is_deno() {
+            "deno install\ndeno task start".to_string()
+        } else {
+            format!("{} install\n{} run build\nnode {}", package_manager, package_manager, self.build_entrypoint())
+        };
+        
+        let quickstart = format!(r#"# {} Quickstart
+
+Copy and paste these commands to get the server running. See README.md for details.
+
+```bash
+cd {}
+{}
+```
+"#,
+            self.project_name,
+            self.options.directory(&self.project_name),
+            run_commands
+        );
+        
+        self.files.write("QUICKSTART.md", quickstart)?;
+        
+        Ok(())
+    }
+}
+
+/// Points local `.js` import specifiers at the `.ts` sources, which is what Deno resolves
+fn deno_local_imports(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            let is_local = ["from \"./", "from \"../", "from \"@/"]
+                .iter()
+                .any(|prefix| line.contains(prefix));
+            match line.strip_suffix(".js\";") {
+                Some(stem) if is_local => format!("{}.ts\";\n", stem),
+                _ => format!("{}\n", line),
+            }
+        })
+        .collect()
+}
+
+/// Prefixes top-level declarations with `export` so they can live in their own module
+fn export_declarations(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            let is_declaration = ["const ", "function ", "async function ", "interface ", "type "]
+                .iter()
+                .any(|keyword| line.starts_with(keyword));
+            if is_declaration {
+                format!("export {}\n", line)
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect()
+}
+
+/// Adds `isError: true` to tool results whose text reports a failure
+fn flag_tool_errors(code: &str) -> String {
+    let mut flagged = String::new();
+    let mut in_failure = false;
+    for line in code.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("text: \"Failed") || trimmed.starts_with("text: `Failed") {
+            in_failure = true;
+        } else if in_failure && trimmed == "};" {
+            let indent = &line[..line.len() - trimmed.len()];
+            flagged.push_str(&format!("{}  isError: true,\n", indent));
+            in_failure = false;
+        }
+        flagged.push_str(line);
+        flagged.push('\n');
+    }
+    flagged
+}
+
+/// Indents every non-empty line by the given number of spaces
+fn indent(code: &str, spaces: usize) -> String {
+    let padding = " ".repeat(spaces);
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("{}{}\n", padding, line)
+            }
+        })
+        .collect()
+}
+
+/// Constants shared by the tools generated from an OpenAPI spec
+fn openapi_constants(openapi: &OpenApiTools) -> String {
+    format!("const API_BASE = {};\n", js_string(&openapi.base_url))
+}
+
+/// Renders a `server.tool(...)` registration for every GET operation of an OpenAPI spec
+fn openapi_tool_registrations(openapi: &OpenApiTools) -> String {
+    let mut registrations = Vec::new();
+    for operation in &openapi.operations {
+        let mut schema = String::new();
+        let mut query = String::new();
+        let mut path = operation.path.clone();
+        
+        for parameter in &operation.parameters {
+            let mut field = match parameter.kind {
+                ParamKind::String => "z.string()".to_string(),
+                ParamKind::Integer => "z.number().int()".to_string(),
+                ParamKind::Number => "z.number()".to_string(),
+                ParamKind::Boolean => "z.boolean()".to_string(),
+            };
+            if !parameter.required {
+                field.push_str(".optional()");
+            }
+            if let Some(description) = &parameter.description {
+                field.push_str(&format!(".describe({})", js_string(description)));
+            }
+            schema.push_str(&format!("    {}: {},\n", js_string(&parameter.name), field));
+            
+            let value = format!("args[{}]", js_string(&parameter.name));
+            if parameter.in_path {
+                path = path.replace(
+                    &format!("{{{}}}", parameter.name),
+                    &format!("${{encodeURIComponent(String({}))}}", value),
+                );
+            } else {
+                query.push_str(&format!(
+                    "    if ({} !== undefined) url.searchParams.set({}, String({}));\n",
+                    value, js_string(&parameter.name), value
+                ));
+            }
+        }
+        
+        registrations.push(format!(r#"server.tool(
+  {},
+  {},
+  {{
+{}  }},
+  async (args) => {{
+    const url = new URL(`${{API_BASE}}{}`);
+{}
+    try {{
+      const response = await fetch(url);
+      const text = await response.text();
+      return {{
+        content: [{{ type: "text", text }}],
+        isError: !response.ok,
+      }};
+    }} catch (error) {{
+      return {{
+        content: [{{ type: "text", text: `Request to ${{url.href}} failed: ${{error instanceof Error ? error.message : String(error)}}` }}],
+        isError: true,
+      }};
+    }}
+  }},
+);
+"#, js_string(&operation.name), js_string(&operation.description), schema, path, query));
+    }
+    registrations.join("\n")
+}
+
+/// Quotes a value as a JavaScript string literal
+fn js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Renders `"name": "version"` lines for a package.json dependency object
+fn json_entries(dependencies: &[(&str, &str)]) -> String {
+    dependencies
+        .iter()
+        .map(|(name, version)| format!("    \"{}\": \"{}\"", name, version))
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+/// npm package of the MCP TypeScript SDK
+const MCP_SDK_PACKAGE: &str = "@modelcontextprotocol/sdk";
+
+/// Permissions the Deno tasks run the server with (NWS requests and PORT/API key lookups)
+const DENO_PERMISSIONS: &str = "--allow-net --allow-env";
+
+/// Shebang and imports for the single-file server
+const SERVER_IMPORTS: &str = r#"#!/usr/bin/env node
+import { McpServer } from "@modelcontextprotocol/sdk/server/mcp.js";
+import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
+import { z } from "zod";
+"#;
+
+/// Import of the stdio transport, replaced when another transport is selected
+const STDIO_IMPORT: &str = "import { StdioServerTransport } from \"@modelcontextprotocol/sdk/server/stdio.js\";\n";
+
+/// Imports needed by the WebSocket transport
+const WS_IMPORTS: &str = r#"import type { Transport } from "@modelcontextprotocol/sdk/shared/transport.js";
+import { JSONRPCMessageSchema, type JSONRPCMessage } from "@modelcontextprotocol/sdk/types.js";
+import { WebSocketServer, type WebSocket } from "ws";
+"#;
+
+/// Imports needed by the SSE transport
+const SSE_IMPORTS: &str = r#"import { SSEServerTransport } from "@modelcontextprotocol/sdk/server/sse.js";
+import express from "express";
+"#;
+
+/// Imports needed by the Streamable HTTP transport
+const HTTP_IMPORTS: &str = r#"import { StreamableHTTPServerTransport } from "@modelcontextprotocol/sdk/server/streamableHttp.js";
+import express from "express";
+import { randomUUID } from "node:crypto";
+"#;
+
+/// NWS API constants shared by the weather example
+const NWS_CONSTANTS: &str = r#"const NWS_API_BASE = "https://api.weather.gov";
+const USER_AGENT = "weather-app/1.0";
+"#;
+
+/// MCP server construction
+const SERVER_INSTANCE: &str = r#"// Create server instance
+const server = new McpServer({
+  name: "weather",
+  version: "1.0.0",
+});
+"#;
+
+/// NWS request helper
+const NWS_REQUEST: &str = r#"// Helper function for making NWS API requests
+async function makeNWSRequest<T>(url: string): Promise<T | null> {
+  const headers = {
+    "User-Agent": USER_AGENT,
+    Accept: "application/geo+json",
+  };
+
+  try {
+    const response = await fetch(url, { headers });
+    if (!response.ok) {
+      throw new Error(`HTTP error! status: ${response.status}`);
+    }
+    return (await response.json()) as T;
+  } catch (error) {
+    console.error("Error making NWS request:", error);
+    return null;
+  }
+}
+"#;
+
+/// Alert shape read by the formatter
+const ALERT_FEATURE: &str = r#"interface AlertFeature {
+  properties: {
+    event?: string;
     areaDesc?: string;
     severity?: string;
     status?: string;
@@ -382,8 +1697,10 @@ interface AlertFeature {
     instruction?: string;
   };
 }
+"#;
 
-// Format alert data
+/// Alert formatting shared by the alert tools
+const ALERT_FORMATTER: &str = r#"// Format alert data
 function formatAlert(feature: AlertFeature): string {
   const props = feature.properties;
   return [
@@ -395,8 +1712,10 @@ function formatAlert(feature: AlertFeature): string {
     "---",
   ].join("\n");
 }
+"#;
 
-interface ForecastPeriod {
+/// Types of the NWS responses the weather tools read
+const NWS_RESPONSE_TYPES: &str = r#"interface ForecastPeriod {
   name?: string;
   temperature?: number;
   temperatureUnit?: string;
@@ -406,24 +1725,111 @@ interface ForecastPeriod {
   detailedForecast?: string;
 }
 
-interface AlertsResponse {
-  features: AlertFeature[];
-}
+interface AlertsResponse {
+  features: AlertFeature[];
+}
+
+interface PointsResponse {
+  properties: {
+    forecast?: string;
+  };
+}
+
+interface ForecastResponse {
+  properties: {
+    periods: ForecastPeriod[];
+  };
+}
+"#;
+
+/// NWS request helper validating the response against a zod schema
+const NWS_REQUEST_VALIDATED: &str = r#"// Helper function for making NWS API requests; responses that aren't JSON or
+// don't match the schema are logged and treated like failed requests
+async function makeNWSRequest<T>(url: string, schema: z.ZodType<T>): Promise<T | null> {
+  const headers = {
+    "User-Agent": USER_AGENT,
+    Accept: "application/geo+json",
+  };
+
+  try {
+    const response = await fetch(url, { headers });
+    if (!response.ok) {
+      throw new Error(`HTTP error! status: ${response.status}`);
+    }
+    const result = schema.safeParse(await response.json());
+    if (!result.success) {
+      console.error("Unexpected NWS response:", result.error.message);
+      return null;
+    }
+    return result.data;
+  } catch (error) {
+    console.error("Error making NWS request:", error);
+    return null;
+  }
+}
+"#;
+
+/// Zod schemas of the NWS responses the weather tools read, with the types inferred from them
+const NWS_SCHEMAS: &str = r#"// The NWS sends null for missing fields, so optional fields accept null too
+const AlertFeatureSchema = z.object({
+  properties: z.object({
+    event: z.string().nullish(),
+    areaDesc: z.string().nullish(),
+    severity: z.string().nullish(),
+    status: z.string().nullish(),
+    headline: z.string().nullish(),
+    description: z.string().nullish(),
+    instruction: z.string().nullish(),
+  }),
+});
 
-interface PointsResponse {
-  properties: {
-    forecast?: string;
-  };
-}
+type AlertFeature = z.infer<typeof AlertFeatureSchema>;
 
-interface ForecastResponse {
-  properties: {
-    periods: ForecastPeriod[];
-  };
-}
+const ForecastPeriodSchema = z.object({
+  name: z.string().nullish(),
+  temperature: z.number().nullish(),
+  temperatureUnit: z.string().nullish(),
+  windSpeed: z.string().nullish(),
+  windDirection: z.string().nullish(),
+  shortForecast: z.string().nullish(),
+  detailedForecast: z.string().nullish(),
+});
 
-// Register weather tools
-server.tool(
+type ForecastPeriod = z.infer<typeof ForecastPeriodSchema>;
+
+const AlertsResponseSchema = z.object({
+  features: z.array(AlertFeatureSchema),
+});
+
+const PointsResponseSchema = z.object({
+  properties: z.object({
+    forecast: z.string().nullish(),
+  }),
+});
+
+const ForecastResponseSchema = z.object({
+  properties: z.object({
+    periods: z.array(ForecastPeriodSchema),
+  }),
+});
+"#;
+
+/// NWS requests in the tools and their validated replacements, including the multi-file imports
+const VALIDATED_NWS_CALLS: [(&str, &str); 7] = [
+    ("makeNWSRequest<AlertsResponse>(alertsUrl)", "makeNWSRequest(alertsUrl, AlertsResponseSchema)"),
+    ("makeNWSRequest<PointsResponse>(pointsUrl)", "makeNWSRequest(pointsUrl, PointsResponseSchema)"),
+    ("makeNWSRequest<ForecastResponse>(forecastUrl)", "makeNWSRequest(forecastUrl, ForecastResponseSchema)"),
+    (
+        "makeNWSRequest<{\n        weather?: { description?: string }[];\n        main?: { temp?: number; humidity?: number };\n      }>(url)",
+        "makeNWSRequest(\n        url,\n        z.object({\n          weather: z.array(z.object({ description: z.string().nullish() })).nullish(),\n          main: z.object({ temp: z.number().nullish(), humidity: z.number().nullish() }).nullish(),\n        }),\n      )",
+    ),
+    ("type AlertsResponse", "AlertsResponseSchema"),
+    ("type PointsResponse", "PointsResponseSchema"),
+    ("type ForecastResponse", "ForecastResponseSchema"),
+];
+
+/// Weather tool registrations
+const WEATHER_TOOLS: &str = r#"server.tool(
   "get-alerts",
   "Get weather alerts for a state",
   {
@@ -554,8 +1960,10 @@ server.tool(
     };
   },
 );
+"#;
 
-async function main() {
+/// Entrypoint connecting the server over stdio
+const STDIO_MAIN: &str = r#"async function main() {
   const transport = new StdioServerTransport();
   await server.connect(transport);
   console.error("Weather MCP Server running on stdio");
@@ -566,113 +1974,681 @@ main().catch((error) => {
   process.exit(1);
 });
 "#;
-        
-        fs::write(
-            self.project_path.join("src/index.ts"),
-            server_code,
-        ).context("Failed to create src/index.ts")?;
-        
-        Ok(())
-    }
-    
-    fn create_readme(&self) -> Result<()> {
-        let package_manager = match self.tool {
-            Tool::Pnpm => "pnpm",
-            Tool::Yarn => "yarn",
-            Tool::Npm => "npm",
-            _ => "npm",
+
+/// The only tool of the minimal template
+const ECHO_TOOL: &str = r#"// Register tools
+server.tool(
+  "echo",
+  "Echo back the given message",
+  {
+    message: z.string().describe("Message to echo back"),
+  },
+  ({ message }) => {
+    return {
+      content: [
+        {
+          type: "text",
+          text: message,
+        },
+      ],
+    };
+  },
+);
+"#;
+
+/// README description of the weather example
+const WEATHER_README_ABOUT: &str = "This project implements an MCP server that provides weather information via the National Weather Service API. It demonstrates how to create a server that can be used with MCP compatible clients like Claude for Desktop.";
+
+/// README entries for the weather tools
+const WEATHER_README_TOOLS: &str = "- **get-alerts**: Get active weather alerts for a US state\n  - Parameters: `state` (two-letter state code)\n\n- **get-forecast**: Get weather forecast for a location\n  - Parameters: `latitude`, `longitude`\n\n";
+
+/// README example queries for the weather tools
+const WEATHER_README_EXAMPLES: &str = "- \"What's the weather in Sacramento?\"\n- \"What are the active weather alerts in California?\"\n- \"Tell me the forecast for New York (40.7128, -74.0060)\"\n";
+
+/// README description of the minimal template
+const MINIMAL_README_ABOUT: &str = "This project implements a minimal MCP server with a single `echo` tool, ready to be extended with your own tools. It can be used with MCP compatible clients like Claude for Desktop.";
+
+/// README entry for the echo tool
+const MINIMAL_README_TOOLS: &str = "- **echo**: Echo back the given message\n  - Parameters: `message`\n\n";
+
+/// README example query for the echo tool
+const MINIMAL_README_EXAMPLES: &str = "- \"Use the echo tool to repeat 'Hello, MCP!'\"\n";
+
+/// Optional tool showing how to skip registration when an API key is missing
+const CONDITIONS_TOOL: &str = r#"// Registers get-current-conditions only when OPENWEATHER_API_KEY is set, so the
+// server still starts (without the tool) when the key is missing
+function registerConditionsTool(server: McpServer): void {
+  const apiKey = process.env.OPENWEATHER_API_KEY;
+  if (!apiKey) {
+    console.error("OPENWEATHER_API_KEY is not set; skipping the get-current-conditions tool");
+    return;
+  }
+
+  server.tool(
+    "get-current-conditions",
+    "Get current weather conditions for a location",
+    {
+      latitude: z.number().min(-90).max(90).describe("Latitude of the location"),
+      longitude: z.number().min(-180).max(180).describe("Longitude of the location"),
+    },
+    async ({ latitude, longitude }) => {
+      const url = `https://api.openweathermap.org/data/2.5/weather?lat=${latitude}&lon=${longitude}&units=metric&appid=${apiKey}`;
+      const data = await makeNWSRequest<{
+        weather?: { description?: string }[];
+        main?: { temp?: number; humidity?: number };
+      }>(url);
+
+      if (!data) {
+        return {
+          content: [
+            {
+              type: "text",
+              text: "Failed to retrieve current conditions",
+            },
+          ],
         };
-        
-        let readme = format!(r#"# {}
+      }
 
-A Model Context Protocol (MCP) server implementation.
+      const conditionsText = [
+        `Current conditions for ${latitude}, ${longitude}:`,
+        `Conditions: ${data.weather?.[0]?.description || "Unknown"}`,
+        `Temperature: ${data.main?.temp ?? "Unknown"}°C`,
+        `Humidity: ${data.main?.humidity ?? "Unknown"}%`,
+      ].join("\n");
 
-## About
+      return {
+        content: [
+          {
+            type: "text",
+            text: conditionsText,
+          },
+        ],
+      };
+    },
+  );
+}
+"#;
 
-This project implements an MCP server that provides weather information via the National Weather Service API. It demonstrates how to create a server that can be used with MCP compatible clients like Claude for Desktop.
+/// README entry for the optional conditions tool
+const CONDITIONS_README_ENTRY: &str = "- **get-current-conditions**: Get current weather conditions for a location (only registered when `OPENWEATHER_API_KEY` is set)\n  - Parameters: `latitude`, `longitude`\n\n";
 
-## Getting Started
+/// Example tool whose schema shows zod enums, refinements and defaults
+const VALIDATION_TOOL: &str = r#"// Registers search-alerts, whose schema shows richer zod validation: an enum,
+// refinements and optional parameters with defaults. The SDK rejects invalid
+// arguments with a descriptive error before the handler runs.
+function registerValidationExamples(server: McpServer): void {
+  server.tool(
+    "search-alerts",
+    "Search the active weather alerts for a US state",
+    {
+      state: z
+        .string()
+        .regex(/^[A-Z]{2}$/, "Use a two-letter uppercase state code")
+        .describe("Two-letter US state code (e.g. CA, NY)"),
+      severity: z
+        .enum(["Extreme", "Severe", "Moderate", "Minor"])
+        .optional()
+        .describe("Only return alerts of this severity"),
+      keyword: z
+        .string()
+        .trim()
+        .refine((value) => value.length >= 3, "Use at least 3 non-blank characters")
+        .optional()
+        .describe("Only return alerts whose event mentions this text"),
+      limit: z
+        .number()
+        .int()
+        .min(1)
+        .max(25)
+        .default(5)
+        .describe("Maximum number of alerts to return"),
+    },
+    async ({ state, severity, keyword, limit }) => {
+      const alertsUrl = `${NWS_API_BASE}/alerts/active/area/${state}`;
+      const alertsData = await makeNWSRequest<AlertsResponse>(alertsUrl);
 
-### Prerequisites
+      if (!alertsData) {
+        return {
+          content: [
+            {
+              type: "text",
+              text: "Failed to retrieve alerts data",
+            },
+          ],
+        };
+      }
 
-- Node.js 16 or newer
-- {} (package manager)
+      const features = (alertsData.features || []).filter(
+        (feature) =>
+          (!severity || feature.properties.severity === severity) &&
+          (!keyword || (feature.properties.event ?? "").toLowerCase().includes(keyword.toLowerCase())),
+      );
+      if (features.length === 0) {
+        return {
+          content: [
+            {
+              type: "text",
+              text: `No active alerts for ${state} match the filters`,
+            },
+          ],
+        };
+      }
 
-### Installation
+      const formattedAlerts = features.slice(0, limit).map(formatAlert);
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Matching alerts for ${state}:\n\n${formattedAlerts.join("\n")}`,
+          },
+        ],
+      };
+    },
+  );
+}
+"#;
 
-```bash
-# Install dependencies
-{} install
-```
+/// Example tools built on one shared forecast helper
+const COMPOSITION_TOOLS: &str = r#"// Resolves the grid point and fetches its forecast periods; both tools below
+// reuse this instead of each repeating the two NWS requests
+async function fetchForecastPeriods(latitude: number, longitude: number): Promise<ForecastPeriod[] | null> {
+  const pointsUrl = `${NWS_API_BASE}/points/${latitude.toFixed(4)},${longitude.toFixed(4)}`;
+  const pointsData = await makeNWSRequest<PointsResponse>(pointsUrl);
+  const forecastUrl = pointsData?.properties?.forecast;
+  if (!forecastUrl) {
+    return null;
+  }
 
-### Building the Server
+  const forecastData = await makeNWSRequest<ForecastResponse>(forecastUrl);
+  return forecastData?.properties?.periods ?? null;
+}
 
-```bash
-# Build the TypeScript code
-{} run build
-```
+// Registers two tools composed from fetchForecastPeriods
+function registerForecastComposition(server: McpServer): void {
+  server.tool(
+    "get-forecast-summary",
+    "Get a one-line-per-period summary of the upcoming forecast for a location",
+    {
+      latitude: z.number().min(-90).max(90).describe("Latitude of the location"),
+      longitude: z.number().min(-180).max(180).describe("Longitude of the location"),
+      periods: z.number().int().min(1).max(14).default(3).describe("Number of forecast periods to summarize"),
+    },
+    async ({ latitude, longitude, periods }) => {
+      const forecast = await fetchForecastPeriods(latitude, longitude);
+      if (!forecast || forecast.length === 0) {
+        return {
+          content: [
+            {
+              type: "text",
+              text: `Failed to retrieve forecast for coordinates: ${latitude}, ${longitude}`,
+            },
+          ],
+        };
+      }
 
-### Running the Server
+      const summary = forecast
+        .slice(0, periods)
+        .map((period) => `${period.name || "Unknown"}: ${period.temperature ?? "Unknown"}°${period.temperatureUnit || "F"}, ${period.shortForecast || "No forecast available"}`)
+        .join("\n");
 
-For development:
-```bash
-# Run in development mode with hot reload
-{} run dev
-```
+      return {
+        content: [
+          {
+            type: "text",
+            text: summary,
+          },
+        ],
+      };
+    },
+  );
 
-For Claude for Desktop integration, you'll need to add the server to your Claude configuration. Open `~/Library/Application Support/Claude/claude_desktop_config.json` and add:
+  server.tool(
+    "get-temperature-range",
+    "Get the lowest and highest forecast temperatures for a location",
+    {
+      latitude: z.number().min(-90).max(90).describe("Latitude of the location"),
+      longitude: z.number().min(-180).max(180).describe("Longitude of the location"),
+    },
+    async ({ latitude, longitude }) => {
+      const forecast = await fetchForecastPeriods(latitude, longitude);
+      const temperatures = (forecast ?? [])
+        .map((period) => period.temperature)
+        .filter((temperature): temperature is number => typeof temperature === "number");
+      if (!forecast || temperatures.length === 0) {
+        return {
+          content: [
+            {
+              type: "text",
+              text: `Failed to retrieve forecast temperatures for coordinates: ${latitude}, ${longitude}`,
+            },
+          ],
+        };
+      }
 
-```json
-{{
-  "mcpServers": {{
-    "weather": {{
-      "command": "node",
-      "args": [
-        "/ABSOLUTE/PATH/TO/{}/build/index.js"
-      ]
-    }}
-  }}
-}}
-```
+      const unit = forecast[0].temperatureUnit || "F";
+      return {
+        content: [
+          {
+            type: "text",
+            text: `Over the next ${forecast.length} periods temperatures range from ${Math.min(...temperatures)}°${unit} to ${Math.max(...temperatures)}°${unit}`,
+          },
+        ],
+      };
+    },
+  );
+}
+"#;
 
-Replace `/ABSOLUTE/PATH/TO/{}` with the absolute path to your project.
+/// README entry for the tool composition example
+const COMPOSITION_README_ENTRY: &str = "- **get-forecast-summary**: Summarize the upcoming forecast periods for a location in one line each, built on the same helper as `get-temperature-range`\n  - Parameters: `latitude`, `longitude`, `periods` (1-14, default 3)\n\n- **get-temperature-range**: Get the lowest and highest forecast temperatures for a location\n  - Parameters: `latitude`, `longitude`\n\n";
 
-## Available Tools
+/// Sample static resource, registered alongside the tools
+const RESOURCES: &str = r#"// Registers a static resource that clients can read (resources/read) for context,
+// unlike tools, which the model calls
+function registerResources(server: McpServer): void {
+  server.resource(
+    "app-config",
+    "config://app",
+    {
+      description: "Configuration of this MCP server",
+      mimeType: "application/json",
+    },
+    (uri) => ({
+      contents: [
+        {
+          uri: uri.href,
+          mimeType: "application/json",
+          text: JSON.stringify({ name: "weather", version: "1.0.0" }, null, 2),
+        },
+      ],
+    }),
+  );
+}
+"#;
 
-This MCP server provides the following tools:
+/// README entry for the sample resource
+const RESOURCES_README_ENTRY: &str = "- **config://app** (resource): Configuration of this MCP server as JSON, read by clients via `resources/read` rather than called like a tool\n\n";
 
-- **get-alerts**: Get active weather alerts for a US state
-  - Parameters: `state` (two-letter state code)
+/// Imports used by the pagination example
+const PAGINATION_IMPORTS: &str = "import { ErrorCode, ListResourcesRequestSchema, McpError } from \"@modelcontextprotocol/sdk/types.js\";\n";
 
-- **get-forecast**: Get weather forecast for a location
-  - Parameters: `latitude`, `longitude`
+/// Sample items listed a page at a time, registered after the other resources
+const PAGINATION_EXAMPLE: &str = r#"// Serves resources/list a page at a time, with sample items to page through.
+// McpServer lists every resource at once, so this replaces its resources/list
+// handler; the cursor is opaque to clients and here holds the next page's offset
+function registerPaginationExample(server: McpServer): void {
+  server.resource(
+    "sample-item",
+    new ResourceTemplate("items://{id}", { list: undefined }),
+    {
+      description: "One of the paginated sample items",
+      mimeType: "text/plain",
+    },
+    (uri, { id }) => ({
+      contents: [
+        {
+          uri: uri.href,
+          mimeType: "text/plain",
+          text: `Contents of ${id}`,
+        },
+      ],
+    }),
+  );
 
-## Example Queries for Claude
+  // Every listed resource, including the ones registered with server.resource
+  const resources = [
+    { uri: "config://app", name: "app-config", mimeType: "application/json" },
+    ...Array.from({ length: 25 }, (_, i) => ({
+      uri: `items://item-${i + 1}`,
+      name: `item-${i + 1}`,
+      mimeType: "text/plain",
+    })),
+  ];
+  const pageSize = 10;
 
-After connecting your server to Claude for Desktop, you can ask questions like:
+  server.server.setRequestHandler(ListResourcesRequestSchema, (request) => {
+    const cursor = request.params?.cursor;
+    const offset = cursor === undefined ? 0 : Number(cursor);
+    if (!Number.isInteger(offset) || offset < 0 || offset > resources.length) {
+      throw new McpError(ErrorCode.InvalidParams, `Invalid cursor: ${cursor}`);
+    }
 
-- "What's the weather in Sacramento?"
-- "What are the active weather alerts in California?"
-- "Tell me the forecast for New York (40.7128, -74.0060)"
+    const end = offset + pageSize;
+    return {
+      resources: resources.slice(offset, end),
+      nextCursor: end < resources.length ? String(end) : undefined,
+    };
+  });
+}
+"#;
 
-## License
+/// README entry for the pagination example
+const PAGINATION_README_ENTRY: &str = "- **items://{id}** (resource template): 25 sample items; `resources/list` returns 10 resources per page plus a `nextCursor` to pass back as `cursor` for the next page\n\n";
 
-MIT
-"#,
-            self.project_name,
-            package_manager,
-            package_manager,
-            package_manager,
-            package_manager,
-            self.project_name,
-            self.project_name
-        );
-        
-        fs::write(
-            self.project_path.join("README.md"),
-            readme,
-        ).context("Failed to create README.md")?;
-        
-        Ok(())
+/// ESLint flat config extending the recommended and type-checked typescript-eslint rules
+const ESLINT_CONFIG: &str = r#"// @ts-check
+import { dirname } from "node:path";
+import { fileURLToPath } from "node:url";
+import eslint from "@eslint/js";
+import tseslint from "typescript-eslint";
+
+export default tseslint.config(
+  { ignores: ["build/"] },
+  eslint.configs.recommended,
+  tseslint.configs.recommendedTypeChecked,
+  {
+    languageOptions: {
+      parserOptions: {
+        projectService: true,
+        tsconfigRootDir: dirname(fileURLToPath(import.meta.url)),
+      },
+    },
+  },
+  {
+    files: ["**/*.js"],
+    extends: [tseslint.configs.disableTypeChecked],
+  },
+);
+"#;
+
+/// Vitest configuration picking up the tests next to the sources
+const VITEST_CONFIG: &str = r#"import { defineConfig } from "vitest/config";
+
+export default defineConfig({
+  test: {
+    include: ["src/**/*.test.ts"],
+  },
+});
+"#;
+
+/// Example test exercising the alert formatter
+const EXAMPLE_TEST: &str = r#"import { describe, expect, it } from "vitest";
+import { formatAlert, type AlertFeature } from "./lib/nws.js";
+
+describe("formatAlert", () => {
+  it("formats the alert fields", () => {
+    const alert: AlertFeature = {
+      properties: {
+        event: "Flood Warning",
+        areaDesc: "Travis County",
+        severity: "Severe",
+        description: "Flooding is occurring.",
+        instruction: "Move to higher ground.",
+      },
+    };
+
+    expect(formatAlert(alert)).toBe(
+      [
+        "Event: Flood Warning",
+        "Area: Travis County",
+        "Severity: Severe",
+        "Description: Flooding is occurring....",
+        "Instructions: Move to higher ground.",
+        "---",
+      ].join("\n"),
+    );
+  });
+
+  it("falls back to placeholders for missing fields", () => {
+    const formatted = formatAlert({ properties: {} });
+
+    expect(formatted).toContain("Event: Unknown");
+    expect(formatted).toContain("Description: No description");
+    expect(formatted).toContain("Instructions: No specific instructions provided");
+  });
+});
+"#;
+
+/// Sample prompt template, registered alongside the tools
+const PROMPTS: &str = r#"// Registers a prompt template that clients can offer to users (prompts/get),
+// filling in its arguments to produce the messages sent to the model
+function registerPrompts(server: McpServer): void {
+  server.prompt(
+    "summarize",
+    "Summarize a piece of text",
+    {
+      text: z.string().describe("Text to summarize"),
+    },
+    ({ text }) => ({
+      messages: [
+        {
+          role: "user",
+          content: {
+            type: "text",
+            text: `Please summarize the following text concisely:\n\n${text}`,
+          },
+        },
+      ],
+    }),
+  );
+}
+"#;
+
+/// README entry for the sample prompt
+const PROMPTS_README_ENTRY: &str = "- **summarize** (prompt): Template asking the model to summarize a piece of text, fetched by clients via `prompts/get`\n  - Arguments: `text`\n\n";
+
+/// Import of the schema wrapper adding completions to a prompt argument
+const COMPLETION_IMPORTS: &str = "import { completable } from \"@modelcontextprotocol/sdk/server/completable.js\";\n";
+
+/// Prompt whose `state` argument is completed from the US state codes
+const COMPLETION_EXAMPLE: &str = r#"// Two-letter codes suggested while the user types the `state` argument
+const US_STATES = [
+  "AK", "AL", "AR", "AZ", "CA", "CO", "CT", "DC", "DE", "FL", "GA", "HI", "IA",
+  "ID", "IL", "IN", "KS", "KY", "LA", "MA", "MD", "ME", "MI", "MN", "MO", "MS",
+  "MT", "NC", "ND", "NE", "NH", "NJ", "NM", "NV", "NY", "OH", "OK", "OR", "PA",
+  "RI", "SC", "SD", "TN", "TX", "UT", "VA", "VT", "WA", "WI", "WV", "WY",
+];
+
+// Registers a prompt whose `state` argument clients can autocomplete: they call
+// completion/complete with what the user typed so far and offer the returned values
+function registerCompletionExample(server: McpServer): void {
+  server.prompt(
+    "state-alerts",
+    "Ask about the active weather alerts of a US state",
+    {
+      state: completable(z.string().describe("Two-letter US state code"), (value) =>
+        US_STATES.filter((code) => code.startsWith(value.toUpperCase())),
+      ),
+    },
+    ({ state }) => ({
+      messages: [
+        {
+          role: "user",
+          content: {
+            type: "text",
+            text: `What are the active weather alerts in ${state}?`,
+          },
+        },
+      ],
+    }),
+  );
+}
+"#;
+
+/// README entry for the prompt with an autocompleted argument
+const COMPLETION_README_ENTRY: &str = "- **state-alerts** (prompt): Asks about the active weather alerts of a US state, with the `state` argument autocompleted through `completion/complete`\n  - Arguments: `state`\n\n";
+
+/// README entry for the input validation example tool
+const VALIDATION_README_ENTRY: &str = "- **search-alerts**: Search the active weather alerts for a US state, showing richer input validation\n  - Parameters: `state` (uppercase two-letter code), `severity` (optional enum), `keyword` (optional, at least 3 characters), `limit` (1-25, default 5)\n\n";
+
+/// Server-side WebSocket transport and listener; the SDK only ships a WebSocket client
+const WS_MAIN: &str = r#"// MCP transport over a single WebSocket connection
+class WebSocketServerTransport implements Transport {
+  onclose?: () => void;
+  onerror?: (error: Error) => void;
+  onmessage?: (message: JSONRPCMessage) => void;
+
+  constructor(private socket: WebSocket) {}
+
+  start(): Promise<void> {
+    this.socket.on("message", (data) => {
+      try {
+        // ws hands over a message as a buffer, an ArrayBuffer or a list of fragments
+        const text = Array.isArray(data)
+          ? Buffer.concat(data).toString()
+          : Buffer.from(data instanceof ArrayBuffer ? new Uint8Array(data) : data).toString();
+        this.onmessage?.(JSONRPCMessageSchema.parse(JSON.parse(text)));
+      } catch (error) {
+        this.onerror?.(error as Error);
+      }
+    });
+    this.socket.on("error", (error) => this.onerror?.(error));
+    this.socket.on("close", () => this.onclose?.());
+    return Promise.resolve();
+  }
+
+  send(message: JSONRPCMessage): Promise<void> {
+    this.socket.send(JSON.stringify(message));
+    return Promise.resolve();
+  }
+
+  close(): Promise<void> {
+    this.socket.close();
+    return Promise.resolve();
+  }
+}
+
+const PORT = Number(process.env.PORT ?? 8080);
+
+function main() {
+  const wss = new WebSocketServer({ host: "127.0.0.1", port: PORT });
+  let connected = false;
+
+  wss.on("connection", (socket) => {
+    // A server instance talks to one client at a time
+    if (connected) {
+      socket.close(1013, "Server busy");
+      return;
+    }
+    connected = true;
+    socket.on("close", () => {
+      connected = false;
+    });
+    void server.connect(new WebSocketServerTransport(socket));
+  });
+
+  console.error(`Weather MCP Server listening on ws://127.0.0.1:${PORT}`);
+}
+
+main();
+"#;
+
+/// Express app serving the SDK's SSE transport: an event stream on /sse and client messages on /messages
+const SSE_MAIN: &str = r#"const PORT = Number(process.env.PORT ?? 8080);
+
+function main() {
+  const app = express();
+  let transport: SSEServerTransport | undefined;
+
+  // Clients open an event stream here and post their messages to /messages
+  app.get("/sse", (_req, res) => {
+    // A server instance talks to one client at a time
+    if (transport) {
+      res.status(503).send("Server busy");
+      return;
+    }
+    transport = new SSEServerTransport("/messages", res);
+    res.on("close", () => {
+      transport = undefined;
+    });
+    void server.connect(transport);
+  });
+
+  app.post("/messages", (req, res) => {
+    if (!transport || req.query.sessionId !== transport.sessionId) {
+      res.status(404).send("Unknown session");
+      return;
     }
-} 
\ No newline at end of file
+    void transport.handlePostMessage(req, res);
+  });
+
+  app.listen(PORT, "127.0.0.1", () => {
+    console.error(`Weather MCP Server listening on http://127.0.0.1:${PORT}/sse`);
+  });
+}
+
+main();
+"#;
+
+/// Express app serving the SDK's Streamable HTTP transport on /mcp
+const HTTP_MAIN: &str = r#"const PORT = Number(process.env.PORT ?? 3000);
+
+async function main() {
+  const app = express();
+  app.use(express.json());
+
+  // A single session: the transport assigns its ID when a client initializes
+  const transport = new StreamableHTTPServerTransport({
+    sessionIdGenerator: () => randomUUID(),
+  });
+  await server.connect(transport);
+
+  // Clients POST messages, GET a notification stream and DELETE to end the session
+  app.all("/mcp", (req, res) => {
+    void transport.handleRequest(req, res, req.body);
+  });
+
+  app.listen(PORT, "127.0.0.1", () => {
+    console.error(`Weather MCP Server listening on http://127.0.0.1:${PORT}/mcp`);
+  });
+}
+
+main().catch((error) => {
+  console.error("Fatal error in main():", error);
+  process.exit(1);
+});
+"#;
+
+/// OpenTelemetry packages and versions added by --with-otel
+const OTEL_PACKAGES: &[(&str, &str)] = &[
+    ("@opentelemetry/api", "^1.9.0"),
+    ("@opentelemetry/exporter-trace-otlp-http", "^0.57.0"),
+    ("@opentelemetry/sdk-node", "^0.57.0"),
+];
+
+/// Imports needed by the OpenTelemetry setup
+const OTEL_IMPORTS: &str = r#"import { SpanStatusCode, trace } from "@opentelemetry/api";
+import { OTLPTraceExporter } from "@opentelemetry/exporter-trace-otlp-http";
+import { NodeSDK } from "@opentelemetry/sdk-node";
+"#;
+
+/// OpenTelemetry SDK setup wrapping every tool registered afterwards in a span
+const TELEMETRY: &str = r#"// OpenTelemetry tracing of tool calls, exported over OTLP/HTTP (configure the
+// collector with the standard OTEL_EXPORTER_OTLP_* environment variables)
+const telemetry = new NodeSDK({
+  serviceName: process.env.OTEL_SERVICE_NAME ?? "weather",
+  traceExporter: new OTLPTraceExporter(),
+});
+telemetry.start();
+for (const signal of ["SIGINT", "SIGTERM"] as const) {
+  process.on(signal, () => {
+    void telemetry.shutdown().finally(() => process.exit(0));
+  });
+}
+const tracer = trace.getTracer("weather");
+
+// Wrap the callback of every tool registered from here on in a span named after the tool
+type ToolRegistration = (...args: unknown[]) => unknown;
+const registerTool = server.tool.bind(server) as unknown as ToolRegistration;
+(server as unknown as { tool: ToolRegistration }).tool = (...args: unknown[]) => {
+  const name = String(args[0]);
+  const callback = args[args.length - 1] as (...callbackArgs: unknown[]) => Promise<unknown>;
+  args[args.length - 1] = (...callbackArgs: unknown[]) =>
+    tracer.startActiveSpan(`tool ${name}`, async (span) => {
+      try {
+        const result = await callback(...callbackArgs);
+        if ((result as { isError?: boolean }).isError) {
+          span.setStatus({ code: SpanStatusCode.ERROR });
+        }
+        return result;
+      } catch (error) {
+        span.recordException(error as Error);
+        span.setStatus({ code: SpanStatusCode.ERROR });
+        throw error;
+      } finally {
+        span.end();
+      }
+    });
+  return registerTool(...args);
+};
+"#;
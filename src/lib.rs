@@ -1,4 +1,9 @@
-use clap::{Parser, ValueEnum};
+use anyhow::{anyhow, Context, Result};
+use clap::parser::ValueSource;
+use clap::{ArgAction, ArgMatches, Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
 
 pub mod generators;
 pub mod utils;
@@ -10,24 +15,163 @@ pub enum Language {
     Python,
     Ts,
     Typescript,
+    Go,
+    Golang,
+    Rust,
 }
 
+/// Languages under their canonical names, in the order the wizard and `mcpc list` show them
+pub const LANGUAGES: [(&str, Language); 4] = [
+    ("typescript", Language::Typescript),
+    ("python", Language::Python),
+    ("go", Language::Go),
+    ("rust", Language::Rust),
+];
+
 /// Supported package manager tools
 #[derive(Debug, Clone, ValueEnum)]
 pub enum Tool {
     Uv,
+    /// Poetry, declaring dependencies in `[tool.poetry]` instead of requirements.txt
+    Poetry,
+    /// pip in a virtual environment created by the standard library `venv` module
+    Pip,
     Pnpm,
     Yarn,
     Npm,
+    Bun,
+    /// Deno, which runs the TypeScript sources directly with npm imports mapped in deno.json
+    Deno,
+    /// Go modules (`go mod tidy`)
+    GoMod,
+    /// Cargo (`cargo fetch`)
+    Cargo,
+}
+
+/// Module system used by generated TypeScript projects
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum TsModuleType {
+    #[default]
+    Esm,
+    Cjs,
+}
+
+/// How Yarn Berry installs packages (`nodeLinker` in .yarnrc.yml)
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum YarnLinker {
+    /// Plug'n'Play, resolving packages from .pnp.cjs without a node_modules folder
+    Pnp,
+    /// A classic node_modules folder, which every MCP SDK setup supports
+    #[default]
+    NodeModules,
+}
+
+/// Example code the generated server starts from
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum Template {
+    /// Weather tools backed by the National Weather Service API
+    #[default]
+    Weather,
+    /// A single `echo` tool and nothing else
+    Minimal,
+}
+
+/// Transport the generated server uses to talk to clients
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum Transport {
+    /// Standard input/output, for clients that launch the server themselves
+    #[default]
+    Stdio,
+    /// WebSocket server listening on --port
+    Ws,
+    /// Server-Sent Events over HTTP, listening on --port
+    Sse,
+    /// Streamable HTTP on a single /mcp endpoint, listening on --port
+    Http,
+}
+
+/// License the generated project is published under
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum License {
+    #[default]
+    Mit,
+    #[value(name = "apache-2.0")]
+    Apache2_0,
+    #[value(name = "bsd-3-clause")]
+    Bsd3Clause,
+    /// No LICENSE file and no license in the manifests
+    None,
+}
+
+/// When to use colored output
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when writing to a terminal outside of CI
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Newline style of the generated files
+#[derive(Debug, Clone, Default, ValueEnum)]
+pub enum LineEndings {
+    #[default]
+    Lf,
+    Crlf,
+    /// CRLF on Windows, LF elsewhere
+    Auto,
+}
+
+/// CI service to generate a workflow for
+#[derive(Debug, Clone, ValueEnum)]
+pub enum Ci {
+    /// GitHub Actions (`.github/workflows/ci.yml`)
+    Github,
+}
+
+/// Subcommands run instead of generating a project
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Print the supported languages, the package manager tools of each and its default tool
+    List {
+        /// Print the table as a JSON array
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 /// CLI arguments for the mcpc command
 #[derive(Parser, Debug)]
 #[command(name = "mcpc")]
 #[command(about = "Generate MCP server project templates", long_about = None)]
+#[command(args_conflicts_with_subcommands = true, disable_help_subcommand = true)]
 pub struct Cli {
-    /// Name of the project
-    pub project_name: String,
+    /// Name of the project (prompted for when omitted)
+    pub project_name: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Directory to generate the project in, when it should differ from the project name (e.g. for a scoped npm package name)
+    #[arg(long, value_name = "DIR")]
+    pub path: Option<PathBuf>,
+
+    /// Prompt for the project name, language and package manager
+    #[arg(short, long)]
+    pub interactive: bool,
+
+    /// Print the supported languages and exit
+    #[arg(long)]
+    pub list_languages: bool,
+
+    /// Print the supported package manager tools and exit
+    #[arg(long)]
+    pub list_tools: bool,
+
+    /// Print the --list-languages and --list-tools output as a JSON array
+    #[arg(long)]
+    pub json: bool,
 
     /// Programming language to use
     #[arg(short, long, value_enum, default_value = "typescript")]
@@ -36,6 +180,362 @@ pub struct Cli {
     /// Package manager tool to use
     #[arg(short, long, value_enum)]
     pub tool: Option<Tool>,
+
+    /// Author name for the manifests (default: git's user.name)
+    #[arg(long, value_name = "NAME")]
+    pub author: Option<String>,
+
+    /// Author email for the manifests (default: git's user.email)
+    #[arg(long)]
+    pub email: Option<String>,
+
+    /// License written to LICENSE and declared in the manifests
+    #[arg(long, value_enum, default_value = "mit")]
+    pub license: License,
+
+    /// Module system for TypeScript projects
+    #[arg(long, value_enum, default_value = "esm")]
+    pub ts_module_type: TsModuleType,
+
+    /// Yarn Berry linker written to .yarnrc.yml (requires --tool yarn, default: node-modules)
+    #[arg(long, value_enum)]
+    pub yarn_linker: Option<YarnLinker>,
+
+    /// Example code the server starts from
+    #[arg(long, value_enum, default_value = "weather")]
+    pub template: Template,
+
+    /// Transport the generated server listens on
+    #[arg(long, value_enum, default_value = "stdio")]
+    pub transport: Transport,
+
+    /// Port for network transports (default: 8080, or 3000 for http)
+    #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+    pub port: Option<u16>,
+
+    /// Name the MCP server after the project directory (sanitized); when false it is named "weather"
+    #[arg(long, value_name = "BOOL", default_value_t = true, action = ArgAction::Set)]
+    pub server_name_from_dir: bool,
+
+    /// Base URL of the weather API used by the example server
+    #[arg(long, value_name = "URL")]
+    pub weather_api: Option<String>,
+
+    /// Also generate a QUICKSTART.md with just the commands needed to get the server running
+    #[arg(long)]
+    pub readme_quickstart_only: bool,
+
+    /// Omit the Claude for Desktop integration section from the generated README
+    #[arg(long)]
+    pub skip_readme_integration: bool,
+
+    /// Generate tools for the GET operations of an OpenAPI spec (file path or URL)
+    #[arg(long, value_name = "SPEC")]
+    pub with_openapi_tool: Option<String>,
+
+    /// File name for the server entrypoint (default: index.ts for TypeScript, server.py for Python)
+    #[arg(long, value_name = "NAME")]
+    pub entrypoint: Option<String>,
+
+    /// Report failures of the example tools as MCP isError results instead of plain text
+    #[arg(long)]
+    pub tool_error_handling: bool,
+
+    /// Add an example tool that is only registered when its API key environment variable is set
+    #[arg(long)]
+    pub with_graceful_degradation: bool,
+
+    /// Configure an `@/*` import alias for `src/*` in TypeScript projects (implies --with-multi-file)
+    #[arg(long)]
+    pub with_typescript_path_aliases: bool,
+
+    /// Add an example tool whose schema shows enums, refinements and optional parameters with defaults
+    #[arg(long)]
+    pub with_input_validation_examples: bool,
+
+    /// Add two example tools built on a shared forecast helper, showing how tools reuse logic
+    #[arg(long)]
+    pub with_multi_tool_example: bool,
+
+    /// Also register a sample `config://app` resource alongside the tools
+    #[arg(long)]
+    pub with_resources: bool,
+
+    /// Page through the resources in resources/list with a cursor (requires --with-resources)
+    #[arg(long)]
+    pub with_pagination_example: bool,
+
+    /// Also register a sample `summarize` prompt template alongside the tools
+    #[arg(long)]
+    pub with_prompts: bool,
+
+    /// Add a prompt whose `state` argument clients can autocomplete (requires --with-prompts)
+    #[arg(long)]
+    pub with_completion_example: bool,
+
+    /// Add a test setup with an example test (Vitest for TypeScript, implies --with-multi-file; pytest for Python)
+    #[arg(long)]
+    pub with_tests: bool,
+
+    /// Add a linter setup (ESLint with typescript-eslint for TypeScript, ruff for Python)
+    #[arg(long)]
+    pub with_lint: bool,
+
+    /// Split the generated server into separate entrypoint, tool and helper modules
+    #[arg(long)]
+    pub with_multi_file: bool,
+
+    /// Register a console script for the Python server so `pip install .` provides a command
+    #[arg(long)]
+    pub python_entry_console_script: bool,
+
+    /// Type the NWS responses in Python projects with TypedDicts instead of plain dicts
+    #[arg(long)]
+    pub python_typing_strict: bool,
+
+    /// Validate the NWS responses in TypeScript projects against zod schemas instead of casting them
+    #[arg(long)]
+    pub with_graceful_json_parse: bool,
+
+    /// Remove the explanatory comments from the generated server code
+    #[arg(long)]
+    pub strip_comments: bool,
+
+    /// Use only the standard library for HTTP requests in Python projects (drops httpx)
+    #[arg(long)]
+    pub minimal_deps: bool,
+
+    /// Share one httpx client between the NWS requests of Python projects, closed on shutdown
+    #[arg(long)]
+    pub python_httpx_client_reuse: bool,
+
+    /// Warn when the installed Node.js architecture does not match the host machine
+    #[arg(long)]
+    pub check_node_arch: bool,
+
+    /// Write the manifests without installing dependencies (e.g. to install in a later, cached CI step)
+    #[arg(long)]
+    pub no_install: bool,
+
+    /// Skip initializing a git repository in the new project (e.g. inside a monorepo)
+    #[arg(long)]
+    pub no_git: bool,
+
+    /// Set the new repository's local git user.name (instead of relying on the global config)
+    #[arg(long, value_name = "NAME")]
+    pub git_user: Option<String>,
+
+    /// Set the new repository's local git user.email
+    #[arg(long, value_name = "EMAIL")]
+    pub git_email: Option<String>,
+
+    /// Declare the server's capabilities explicitly, matching what the template registers
+    #[arg(long)]
+    pub server_capabilities: bool,
+
+    /// Write an mcp-inspector.json that launches the server in the MCP Inspector
+    #[arg(long)]
+    pub with_inspector_config: bool,
+
+    /// Trace tool calls with OpenTelemetry, exporting spans over OTLP
+    #[arg(long)]
+    pub with_otel: bool,
+
+    /// Generate a Dockerfile and .dockerignore for the server
+    #[arg(long)]
+    pub docker: bool,
+
+    /// Generate a CI workflow installing, building and testing the server
+    #[arg(long, value_enum, value_name = "PROVIDER")]
+    pub ci: Option<Ci>,
+
+    /// Generate a .dockerignore for the language without a Dockerfile
+    #[arg(long)]
+    pub with_dockerignore_only: bool,
+
+    /// Use the .gitignore at this path or URL instead of the built-in one
+    #[arg(long, value_name = "URL_OR_PATH")]
+    pub gitignore_from: Option<String>,
+
+    /// Copy a .npmrc into TypeScript projects before installing (e.g. for private registries)
+    #[arg(long, value_name = "PATH")]
+    pub npmrc: Option<PathBuf>,
+
+    /// Generate a Cargo workspace with the Rust server as its first member crate
+    #[arg(long)]
+    pub cargo_workspace: bool,
+
+    /// Fail before generating anything if the selected package manager is missing or does not apply to the language
+    #[arg(long)]
+    pub fail_if_tool_missing: bool,
+
+    /// Print a table of the dependency versions written into the manifests
+    #[arg(long)]
+    pub dependency_table: bool,
+
+    /// Print a tree of the generated project's files once it is created
+    #[arg(long)]
+    pub print_tree_after: bool,
+
+    /// Write a JSON report of the generation (arguments, tool versions, timestamps, file hashes) to this path
+    #[arg(long, value_name = "PATH")]
+    pub summary_json: Option<PathBuf>,
+
+    /// Pack the generated project into a .tar.gz, .tgz or .zip archive instead of leaving a directory (skips git and installing)
+    #[arg(long, value_name = "PATH")]
+    pub output_archive: Option<PathBuf>,
+
+    /// Generate into an existing directory that is empty or only holds OS junk files like .DS_Store
+    #[arg(long)]
+    pub allow_existing_empty: bool,
+
+    /// Append -1, -2, ... to the project name until it names a free directory instead of failing
+    #[arg(long)]
+    pub rename_on_conflict: bool,
+
+    /// Print the files, directories and commands that would be created or run without touching anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Print the package manager commands and ask for confirmation before running them
+    #[arg(long)]
+    pub confirm_install_commands: bool,
+
+    /// Fail if the MCP SDK cannot be imported after installing dependencies
+    #[arg(long)]
+    pub verify_sdk_resolves: bool,
+
+    /// Start the server in development mode once the project is generated
+    #[arg(long)]
+    pub run_after_generate: bool,
+
+    /// Print the next steps for an existing project, detecting its language and package manager
+    #[arg(long)]
+    pub print_next_steps_only: bool,
+
+    /// Only install dependencies into an existing project, detecting its language from its files
+    #[arg(long)]
+    pub deps_only: bool,
+
+    /// Install exactly what the existing lockfile pins, failing instead of updating it (requires --deps-only)
+    #[arg(long)]
+    pub frozen_lockfile: bool,
+
+    /// Use the package manager of the existing project's lockfile instead of the default (requires --deps-only)
+    #[arg(long)]
+    pub detect_package_manager: bool,
+
+    /// When to use colored output (auto disables color in CI and when not writing to a terminal)
+    #[arg(long, value_enum, default_value = "auto")]
+    pub color: ColorChoice,
+
+    /// Newline style of the generated files
+    #[arg(long, value_enum, default_value = "lf")]
+    pub line_endings: LineEndings,
+
+    /// Extra arguments appended to the dependency install command (e.g. "--prerelease=allow")
+    #[arg(long, allow_hyphen_values = true)]
+    pub extra_install_args: Option<String>,
+}
+
+/// File name of the optional defaults file
+pub const CONFIG_FILE: &str = "mcpc.toml";
+
+/// Defaults read from an `mcpc.toml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Default `--language`
+    pub language: Option<String>,
+    /// Default `--tool`
+    pub tool: Option<String>,
+    /// Default `--author`
+    pub author: Option<String>,
+    /// Default `--email`
+    pub email: Option<String>,
+    /// Default `--license`
+    pub license: Option<String>,
+}
+
+/// Loads the first `mcpc.toml` found in the current directory or `$HOME/.config/mcpc/`
+pub fn load_config() -> Result<Option<(PathBuf, Config)>> {
+    let mut candidates = vec![PathBuf::from(CONFIG_FILE)];
+    if let Some(home) = std::env::var_os("HOME") {
+        candidates.push(PathBuf::from(home).join(".config").join("mcpc").join(CONFIG_FILE));
+    }
+
+    let Some(path) = candidates.into_iter().find(|path| path.is_file()) else {
+        return Ok(None);
+    };
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let config = toml::from_str(&contents)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some((path, config)))
+}
+
+/// Parses a config value the same way clap parses the corresponding flag
+fn parse_config_value<T: ValueEnum>(value: &str, key: &str) -> Result<T> {
+    T::from_str(value, true).map_err(|e| anyhow!("invalid {} '{}': {}", key, value, e))
+}
+
+impl Cli {
+    /// Fills in defaults from the config file.
+    ///
+    /// Precedence: flags given on the command line win over the config file, which wins over
+    /// the built-in defaults (and, for the author and email, over the git identity).
+    pub fn apply_config(&mut self, config: &Config, matches: &ArgMatches) -> Result<()> {
+        // `language` and `license` always hold a value, so only replace the clap default
+        let from_command_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        if let Some(language) = config.language.as_deref().filter(|_| !from_command_line("language")) {
+            self.language = parse_config_value(language, "language")?;
+        }
+        if let Some(license) = config.license.as_deref().filter(|_| !from_command_line("license")) {
+            self.license = parse_config_value(license, "license")?;
+        }
+        // A detected lockfile takes precedence over the configured tool
+        if let (None, Some(tool), false) = (&self.tool, &config.tool, self.detect_package_manager) {
+            // A default tool for another language is skipped, so e.g. `-l go` still works
+            let tool: Tool = parse_config_value(tool, "tool")?;
+            if tool.supports(&self.language) {
+                self.tool = Some(tool);
+            }
+        }
+        if self.author.is_none() {
+            self.author = config.author.clone();
+        }
+        if self.email.is_none() {
+            self.email = config.email.clone();
+        }
+
+        Ok(())
+    }
+}
+
+impl Language {
+    /// Whether two languages are the same, treating aliases like `py` and `python` as equal
+    pub fn same_as(&self, other: &Language) -> bool {
+        let canonical = |language: &Language| match language {
+            Language::Py | Language::Python => "python",
+            Language::Ts | Language::Typescript => "typescript",
+            Language::Go | Language::Golang => "go",
+            Language::Rust => "rust",
+        };
+        canonical(self) == canonical(other)
+    }
+}
+
+impl Tool {
+    /// Whether the tool manages projects in the given language
+    pub fn supports(&self, language: &Language) -> bool {
+        match language {
+            Language::Python | Language::Py => matches!(self, Tool::Uv | Tool::Poetry | Tool::Pip),
+            Language::Typescript | Language::Ts => matches!(self, Tool::Pnpm | Tool::Yarn | Tool::Npm | Tool::Bun | Tool::Deno),
+            Language::Go | Language::Golang => matches!(self, Tool::GoMod),
+            Language::Rust => matches!(self, Tool::Cargo),
+        }
+    }
 }
 
 /// Get the default tool for a language
@@ -43,5 +543,7 @@ pub fn get_default_tool(language: &Language) -> Tool {
     match language {
         Language::Python | Language::Py => Tool::Uv,
         Language::Typescript | Language::Ts => Tool::Pnpm,
+        Language::Go | Language::Golang => Tool::GoMod,
+        Language::Rust => Tool::Cargo,
     }
 } 
\ No newline at end of file
@@ -1,26 +1,178 @@
-use clap::Parser;
-use std::path::PathBuf;
-use std::process;
+use anyhow::{bail, Context};
+use clap::{CommandFactory, FromArgMatches};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use colored::Colorize;
 
 use mcpc::{
     Cli, 
+    Command as Subcommand,
+    Language,
+    load_config,
+    Tool,
+    Transport,
     get_default_tool,
-    generators::{Generator, python::PythonGenerator, typescript::TypeScriptGenerator},
-    utils::dependency_checker::check_dependencies,
+    generators::{Generator, GeneratorOptions, ProjectDirGuard, go::GoGenerator, python::{self, PythonGenerator}, rust::RustGenerator, typescript::TypeScriptGenerator},
+    utils::{
+        archive::create_archive,
+        dependency_checker::{check_dependencies, check_node_arch, check_selected_tool},
+        interactive::prompt_project,
+        naming::validate_project_name,
+        output::{configure_color, print_language_table, print_value_names},
+        project_detector::{detect_language, detect_tool, is_effectively_empty},
+    },
 };
 
 fn main() {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    configure_color(&cli.color);
     
-    // Determine the default tool based on the selected language
-    let tool = cli.tool.unwrap_or_else(|| get_default_tool(&cli.language));
+    // `mcpc list` prints which tools go with which language instead of generating a project
+    if let Some(Subcommand::List { json }) = cli.command {
+        print_language_table(json);
+        return;
+    }
+    
+    // Listings for editor integrations print the enum values and exit
+    if cli.list_languages || cli.list_tools {
+        if cli.list_languages {
+            print_value_names::<Language>(cli.json);
+        }
+        if cli.list_tools {
+            print_value_names::<Tool>(cli.json);
+        }
+        return;
+    }
+    
+    // Defaults from mcpc.toml sit between the command-line flags and the built-in defaults
+    let config = load_config().and_then(|config| match config {
+        Some((path, config)) => cli.apply_config(&config, &matches)
+            .with_context(|| format!("Invalid {}", path.display())),
+        None => Ok(()),
+    });
+    if let Err(e) = config {
+        eprintln!("{} {:#}", "❌".red().bold(), e);
+        process::exit(1);
+    }
+    
+    // Ask for whatever is missing instead of failing with a usage error
+    if cli.interactive || cli.project_name.is_none() {
+        if let Err(e) = prompt_project(&mut cli) {
+            eprintln!("{} {:#}", "❌".red().bold(), e);
+            process::exit(1);
+        }
+    }
+    let mut project_name = cli.project_name.clone().unwrap_or_default();
+    
+    // The project lives in the --path directory if given, or else in the directory named after it
+    let mut directory = match &cli.path {
+        Some(path) => path.to_string_lossy().to_string(),
+        None => project_name.clone(),
+    };
+    let project_path = PathBuf::from(&directory);
+    
+    // In deps-only and next-steps-only mode the language comes from the existing project files
+    let existing_project = cli.deps_only || cli.print_next_steps_only;
+    let language = if existing_project {
+        match detect_language(&project_path) {
+            Some(language) => language,
+            None => {
+                eprintln!("{} Could not detect an MCP project in '{}'. Expected a package.json, deno.json, go.mod, Cargo.toml, pyproject.toml or requirements.txt.", 
+                    "❌".red().bold(), 
+                    directory.yellow());
+                process::exit(1);
+            }
+        }
+    } else {
+        cli.language.clone()
+    };
+    
+    // The project name becomes the package name, so reject names its registry would refuse
+    if !existing_project {
+        if let Err(e) = validate_project_name(&project_name, &language) {
+            eprintln!("{} {:#}", "❌".red().bold(), e);
+            process::exit(1);
+        }
+    }
+    
+    // Determine the tool, from the existing project's files when only printing its next steps
+    // or when asked to follow its lockfile
+    let detected_tool = if cli.print_next_steps_only || cli.detect_package_manager {
+        detect_tool(&project_path, &language)
+    } else {
+        None
+    };
+    let tool = cli.tool.clone()
+        .or(detected_tool)
+        .unwrap_or_else(|| get_default_tool(&language));
+    
+    // Move on to the next free `<dir>-N` directory, before the options derive anything from it
+    let reusable = cli.allow_existing_empty && is_effectively_empty(&project_path);
+    if cli.rename_on_conflict && !existing_project && project_path.exists() && !reusable {
+        let renamed = free_directory_name(&directory);
+        println!("{} Directory '{}' already exists, using '{}' instead", 
+            "📁".blue(), 
+            directory.yellow(), 
+            renamed.green().bold());
+        // Without --path the directory is the project name, which is renamed along with it
+        if cli.path.is_some() {
+            cli.path = Some(PathBuf::from(&renamed));
+        } else {
+            project_name = renamed.clone();
+            cli.project_name = Some(renamed.clone());
+        }
+        directory = renamed;
+    }
+    
+    // Validate generator options before touching the system
+    let mut options = match GeneratorOptions::from_cli(&cli) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{} {:#}", "❌".red().bold(), e);
+            process::exit(1);
+        }
+    };
     
-    // Check for required dependencies
-    if let Err(missing_deps) = check_dependencies(&cli.language, &tool) {
-        eprintln!("{}", "❌ Missing required dependencies:".red().bold());
+    // Archives are generated in a temporary directory and packed once complete
+    let staging = match &cli.output_archive {
+        Some(_) => match tempfile::tempdir() {
+            Ok(dir) => Some(dir),
+            Err(e) => {
+                eprintln!("{} Failed to create a temporary directory: {}", "❌".red().bold(), e);
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+    options.output_dir = staging.as_ref().map(|dir| dir.path().to_path_buf());
+    let project_path = options.project_path(&project_name);
+    
+    // Only remind the user how to start an existing project
+    if cli.print_next_steps_only {
+        print_next_steps(&directory, &language, &tool, &options);
+        return;
+    }
+    
+    // Refuse to fall back to another package manager than the one selected
+    if cli.fail_if_tool_missing {
+        if let Err(e) = check_selected_tool(&language, &tool) {
+            eprintln!("{} {}", "❌".red().bold(), e);
+            process::exit(1);
+        }
+    }
+    
+    // Check for required dependencies (a dry run never calls them)
+    let dependencies = if cli.dry_run { Ok(()) } else { check_dependencies(&language, &tool, !options.no_git) };
+    if let Err(missing_deps) = dependencies {
+        eprintln!("{}", "❌ Missing or outdated required dependencies:".red().bold());
         for dep in missing_deps {
-            eprintln!("  - {}", dep.name.yellow());
+            match (&dep.found_version, &dep.required_version) {
+                (Some(found), Some(required)) => eprintln!("  - {} (found {}, need {})", dep.name.yellow(), found, required),
+                _ => eprintln!("  - {}", dep.name.yellow()),
+            }
             if let Some(install_instructions) = dep.install_instructions {
                 eprintln!("    {}: {}", "Install with".blue(), install_instructions.green());
             }
@@ -28,68 +180,252 @@ fn main() {
         process::exit(1);
     }
     
-    // Create the project directory
-    let project_path = PathBuf::from(&cli.project_name);
-    if project_path.exists() {
-        eprintln!("{} Directory '{}' already exists. Please choose another project name.", 
-            "❌".red().bold(), 
-            cli.project_name.yellow());
-        process::exit(1);
+    // Warn about Node.js architecture mismatches before native modules get installed
+    if cli.check_node_arch && matches!(language, Language::Typescript | Language::Ts) {
+        if let Some(warning) = check_node_arch() {
+            eprintln!("{} {}", "⚠️ Warning:".yellow().bold(), warning);
+        }
     }
     
-    // Generate the project
-    let result = match cli.language {
-        mcpc::Language::Python | mcpc::Language::Py => {
-            let generator = PythonGenerator::new(&cli.project_name, &tool);
+    // Reinstall dependencies into the existing project without regenerating it
+    if cli.deps_only {
+        let result = match language {
+            Language::Python | Language::Py => {
+                PythonGenerator::new(&project_name, &tool, &options).init_package_manager()
+            },
+            Language::Typescript | Language::Ts => {
+                TypeScriptGenerator::new(&project_name, &tool, &options).init_package_manager()
+            },
+            Language::Go | Language::Golang => {
+                GoGenerator::new(&project_name, &tool, &options).init_package_manager()
+            },
+            Language::Rust => {
+                RustGenerator::new(&project_name, &tool, &options).init_package_manager()
+            },
+        };
+        
+        if let Err(e) = result {
+            eprintln!("{} Failed to install dependencies: {}", "❌".red().bold(), e);
+            process::exit(1);
+        }
+        return;
+    }
+    
+    // Create the project directory (a dry run only previews, so it may target an existing one)
+    if !cli.dry_run && project_path.exists() {
+        let reusable = is_effectively_empty(&project_path);
+        if !(cli.allow_existing_empty && reusable) {
+            let hint = if reusable { " (it only holds OS junk files; pass --allow-existing-empty to use it)" } else { "" };
+            let choice = if cli.path.is_some() { "--path" } else { "project name" };
+            eprintln!("{} Directory '{}' already exists{}. Please choose another {}.", 
+                "❌".red().bold(), 
+                directory.yellow(),
+                hint,
+                choice);
+            process::exit(1);
+        }
+    }
+    
+    // Generate the project, removing the directory again if a step fails
+    let guard = ProjectDirGuard::new(&project_path, cli.dry_run);
+    let result = match language {
+        Language::Python | Language::Py => {
+            let generator = PythonGenerator::new(&project_name, &tool, &options);
+            generator.generate()
+        },
+        Language::Typescript | Language::Ts => {
+            let generator = TypeScriptGenerator::new(&project_name, &tool, &options);
             generator.generate()
         },
-        mcpc::Language::Typescript | mcpc::Language::Ts => {
-            let generator = TypeScriptGenerator::new(&cli.project_name, &tool);
+        Language::Go | Language::Golang => {
+            let generator = GoGenerator::new(&project_name, &tool, &options);
+            generator.generate()
+        },
+        Language::Rust => {
+            let generator = RustGenerator::new(&project_name, &tool, &options);
             generator.generate()
         },
     };
     
     match result {
+        Ok(_) if cli.dry_run => {},
+        Ok(_) if cli.output_archive.is_some() => {
+            // The staging directory is removed with the rest of the temporary directory
+            guard.disarm();
+            let archive = cli.output_archive.as_deref().unwrap_or(Path::new(""));
+            let prefix = Path::new(&project_name).file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| project_name.clone());
+            if let Err(e) = create_archive(&project_path, &prefix, archive) {
+                eprintln!("{} {:#}", "❌".red().bold(), e);
+                drop(staging);
+                process::exit(1);
+            }
+            println!("{} Successfully packed MCP server project {} into {}", 
+                "✅".green().bold(), 
+                project_name.green().bold(),
+                archive.display().to_string().blue());
+            print_next_steps(&project_name, &language, &tool, &options);
+        },
         Ok(_) => {
+            guard.disarm();
             println!("{} Successfully created MCP server project: {}", 
                 "✅".green().bold(), 
-                cli.project_name.green().bold());
+                project_name.green().bold());
             println!("{} Project location: {}", 
                 "📁".blue().bold(), 
                 project_path.display().to_string().blue());
-            println!("{} Next steps:", "🚀".yellow().bold());
-            println!("  cd {}", cli.project_name);
+            print_next_steps(&directory, &language, &tool, &options);
             
-            match cli.language {
-                mcpc::Language::Python | mcpc::Language::Py => {
-                    println!("  {}", "# Activate virtual environment".dimmed());
-                    println!("  source .venv/bin/activate  # On Windows: .venv\\Scripts\\activate");
-                    println!("  {}", "# Install dependencies".dimmed());
-                    println!("  uv pip install -r requirements.txt");
-                    println!("  {}", "# Run the server".dimmed());
-                    println!("  python server.py");
-                },
-                mcpc::Language::Typescript | mcpc::Language::Ts => {
-                    println!("  {}", "# Install dependencies".dimmed());
-                    match tool {
-                        mcpc::Tool::Pnpm => println!("  pnpm install"),
-                        mcpc::Tool::Yarn => println!("  yarn"),
-                        mcpc::Tool::Npm => println!("  npm install"),
-                        _ => {},
-                    }
-                    println!("  {}", "# Run the server".dimmed());
-                    match tool {
-                        mcpc::Tool::Pnpm => println!("  pnpm dev"),
-                        mcpc::Tool::Yarn => println!("  yarn dev"),
-                        mcpc::Tool::Npm => println!("  npm run dev"),
-                        _ => {},
-                    }
-                },
+            // Start the server right away so the new project can be seen working
+            if cli.run_after_generate && options.no_install {
+                println!("{} Skipping --run-after-generate since --no-install left the dependencies uninstalled", "ℹ️".blue().bold());
+            } else if cli.run_after_generate {
+                let command = dev_command(&project_path, &language, &tool, &options);
+                if let Err(e) = run_dev_server(&project_path, &command) {
+                    eprintln!("{} {:#}", "❌".red().bold(), e);
+                    process::exit(1);
+                }
             }
         },
         Err(e) => {
             eprintln!("{} Failed to create project: {}", "❌".red().bold(), e);
+            // process::exit skips destructors, so clean up explicitly
+            drop(guard);
             process::exit(1);
         }
     }
 }
+
+/// First `<dir>-N` (N = 1, 2, ...) that does not exist yet
+fn free_directory_name(directory: &str) -> String {
+    let base = directory.trim_end_matches(['/', '\\']);
+    (1..)
+        .map(|n| format!("{}-{}", base, n))
+        .find(|name| !Path::new(name).exists())
+        .unwrap_or_default()
+}
+
+/// Prints the commands to install and start a generated project in `directory`
+fn print_next_steps(directory: &str, language: &Language, tool: &Tool, options: &GeneratorOptions) {
+    println!("{} Next steps:", "🚀".yellow().bold());
+    println!("  cd {}", directory);
+    
+    match language {
+        Language::Python | Language::Py if matches!(tool, Tool::Poetry) => {
+            println!("  {}", "# Install dependencies".dimmed());
+            println!("  poetry install");
+            println!("  {}", "# Run the server".dimmed());
+            println!("  poetry run python {}", options.entrypoint_or(python::DEFAULT_ENTRYPOINT));
+        },
+        Language::Python | Language::Py => {
+            if options.no_install {
+                println!("  {}", "# Create virtual environment".dimmed());
+                println!("  {}", if matches!(tool, Tool::Pip) { "python3 -m venv .venv" } else { "uv venv" });
+            }
+            println!("  {}", "# Activate virtual environment".dimmed());
+            println!("  source .venv/bin/activate  # On Windows: .venv\\Scripts\\activate");
+            // Otherwise mcpc already installed them into the virtual environment
+            if options.no_install {
+                println!("  {}", "# Install dependencies".dimmed());
+                println!("  {} install -r requirements.txt", if matches!(tool, Tool::Pip) { "pip" } else { "uv pip" });
+            }
+            println!("  {}", "# Run the server".dimmed());
+            println!("  python {}", options.entrypoint_or(python::DEFAULT_ENTRYPOINT));
+        },
+        Language::Typescript | Language::Ts => {
+            println!("  {}", "# Install dependencies".dimmed());
+            match tool {
+                Tool::Pnpm => println!("  pnpm install"),
+                Tool::Yarn => println!("  yarn"),
+                Tool::Npm => println!("  npm install"),
+                Tool::Bun => println!("  bun install"),
+                Tool::Deno => println!("  deno install"),
+                _ => {},
+            }
+            println!("  {}", "# Run the server".dimmed());
+            match tool {
+                Tool::Pnpm => println!("  pnpm dev"),
+                Tool::Yarn => println!("  yarn dev"),
+                Tool::Npm => println!("  npm run dev"),
+                Tool::Bun => println!("  bun dev"),
+                Tool::Deno => println!("  deno task dev"),
+                _ => {},
+            }
+        },
+        Language::Go | Language::Golang => {
+            if options.no_install {
+                println!("  {}", "# Download dependencies".dimmed());
+                println!("  go mod tidy");
+            }
+            println!("  {}", "# Run the server".dimmed());
+            println!("  go run .");
+        },
+        Language::Rust => {
+            if options.no_install {
+                println!("  {}", "# Download dependencies".dimmed());
+                println!("  cargo fetch");
+            }
+            println!("  {}", "# Run the server".dimmed());
+            println!("  cargo run");
+        },
+    }
+    
+    // Clients connect to network transports instead of launching the server
+    let address = match options.transport {
+        Transport::Stdio => return,
+        Transport::Ws => format!("ws://127.0.0.1:{}", options.port()),
+        Transport::Sse => format!("http://127.0.0.1:{}/sse", options.port()),
+        Transport::Http => format!("http://127.0.0.1:{}/mcp", options.port()),
+    };
+    println!("  {}", format!("# The server listens on {} (PORT overrides the port)", address).dimmed());
+}
+
+/// Command starting a generated project in development mode
+fn dev_command(project_path: &Path, language: &Language, tool: &Tool, options: &GeneratorOptions) -> Vec<String> {
+    let entrypoint = options.entrypoint_or(python::DEFAULT_ENTRYPOINT);
+    let venv_python = project_path.join(python::VENV_PYTHON);
+    let venv_python = std::path::absolute(&venv_python).unwrap_or(venv_python).display().to_string();
+    
+    let command: &[&str] = match language {
+        Language::Python | Language::Py => match tool {
+            Tool::Poetry => &["poetry", "run", "python", entrypoint, "--test"],
+            Tool::Pip => &[&venv_python, entrypoint, "--test"],
+            _ => &["uv", "run", entrypoint, "--test"],
+        },
+        Language::Typescript | Language::Ts => match tool {
+            Tool::Pnpm => &["pnpm", "dev"],
+            Tool::Yarn => &["yarn", "dev"],
+            Tool::Bun => &["bun", "dev"],
+            Tool::Deno => &["deno", "task", "dev"],
+            _ => &["npm", "run", "dev"],
+        },
+        Language::Go | Language::Golang => &["go", "run", "."],
+        Language::Rust => &["cargo", "run"],
+    };
+    command.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// Runs `command` in the project until it exits or is stopped with Ctrl+C
+fn run_dev_server(project_path: &Path, command: &[String]) -> anyhow::Result<()> {
+    // Ctrl+C also reaches the server, so mcpc only records it and waits for the server to exit
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let handler_flag = interrupted.clone();
+    ctrlc::set_handler(move || handler_flag.store(true, Ordering::SeqCst))
+        .context("Failed to install the Ctrl+C handler")?;
+    
+    println!("\n{} Running {} (press Ctrl+C to stop)", "▶️".green().bold(), shell_words::join(command).bold());
+    let status = Command::new(&command[0])
+        .args(&command[1..])
+        .current_dir(project_path)
+        .status()
+        .context(format!("Failed to start {}", command[0]))?;
+    
+    if interrupted.load(Ordering::SeqCst) {
+        println!("\n{} Server stopped", "⏹️".blue().bold());
+    } else if !status.success() {
+        bail!("'{}' exited with {}", shell_words::join(command), status);
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anyhow::{bail, Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+/// Archive formats --output-archive can write, chosen by file extension
+pub enum ArchiveFormat {
+    TarGz,
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Picks the format from the extension of the archive path
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let name = path.file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Ok(Self::TarGz)
+        } else if name.ends_with(".zip") {
+            Ok(Self::Zip)
+        } else {
+            bail!("--output-archive must end in .tar.gz, .tgz or .zip: {}", path.display());
+        }
+    }
+}
+
+/// Packs the contents of `dir` into `archive`, under a top-level `prefix` directory
+pub fn create_archive(dir: &Path, prefix: &str, archive: &Path) -> Result<()> {
+    let format = ArchiveFormat::from_path(archive)?;
+    if let Some(parent) = archive.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .context(format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let file = File::create(archive)
+        .context(format!("Failed to create archive: {}", archive.display()))?;
+    
+    match format {
+        ArchiveFormat::TarGz => {
+            let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+            builder.append_dir_all(prefix, dir)
+                .context(format!("Failed to write archive: {}", archive.display()))?;
+            builder.into_inner()?.finish()?;
+        },
+        ArchiveFormat::Zip => {
+            let mut writer = ZipWriter::new(file);
+            add_zip_entries(&mut writer, dir, prefix)
+                .context(format!("Failed to write archive: {}", archive.display()))?;
+            writer.finish()?;
+        },
+    }
+    
+    Ok(())
+}
+
+/// Adds `dir` to the zip as `prefix/`, recursing in name order and keeping Unix permissions
+fn add_zip_entries<W: Write + io::Seek>(writer: &mut ZipWriter<W>, dir: &Path, prefix: &str) -> Result<()> {
+    writer.add_directory(format!("{}/", prefix), SimpleFileOptions::default())?;
+    
+    let mut entries = fs::read_dir(dir)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let path = entry.path();
+        let name = format!("{}/{}", prefix, entry.file_name().to_string_lossy());
+        if path.is_dir() {
+            add_zip_entries(writer, &path, &name)?;
+            continue;
+        }
+        
+        let mut options = SimpleFileOptions::default();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            options = options.unix_permissions(entry.metadata()?.permissions().mode());
+        }
+        writer.start_file(name, options)?;
+        io::copy(&mut File::open(&path)?, writer)?;
+    }
+    
+    Ok(())
+}
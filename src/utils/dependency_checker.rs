@@ -1,33 +1,53 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use std::process::Command;
 use which::which;
 
+use crate::generators::python::SYSTEM_PYTHON;
 use crate::{Language, Tool};
 
 pub struct Dependency {
     pub name: String,
     pub install_instructions: Option<String>,
+    /// Version that was found when the dependency is installed but too old
+    pub found_version: Option<String>,
+    /// Minimum version the generated projects need
+    pub required_version: Option<String>,
 }
 
+/// Oldest Node.js major version the generated TypeScript projects run on
+pub const MIN_NODE_VERSION: (u64, u64) = (18, 0);
+
+/// Oldest Python version the generated Python projects run on
+pub const MIN_PYTHON_VERSION: (u64, u64) = (3, 10);
+
 /// Check if all required dependencies are installed based on the language and tool
-pub fn check_dependencies(language: &Language, tool: &Tool) -> Result<(), Vec<Dependency>> {
+/// (and git, unless the project is generated without a repository)
+pub fn check_dependencies(language: &Language, tool: &Tool, needs_git: bool) -> Result<(), Vec<Dependency>> {
     let mut missing_deps = Vec::new();
     
     // Check Git
-    if which("git").is_err() {
+    if needs_git && which("git").is_err() {
         missing_deps.push(Dependency {
             name: "Git".to_string(),
             install_instructions: Some("https://git-scm.com/downloads".to_string()),
+            found_version: None,
+            required_version: None,
         });
     }
     
     match language {
         Language::Python | Language::Py => {
             // Check Python
-            if which("python").is_err() && which("python3").is_err() {
+            let python = if which("python3").is_ok() { "python3" } else { "python" };
+            if which(python).is_err() {
                 missing_deps.push(Dependency {
                     name: "Python 3.10+".to_string(),
                     install_instructions: Some("https://www.python.org/downloads/".to_string()),
+                    found_version: None,
+                    required_version: None,
                 });
+            } else if let Some(outdated) = check_minimum_version("Python", python, MIN_PYTHON_VERSION, "https://www.python.org/downloads/") {
+                missing_deps.push(outdated);
             }
             
             // Check UV if tool is UV
@@ -35,47 +55,113 @@ pub fn check_dependencies(language: &Language, tool: &Tool) -> Result<(), Vec<De
                 missing_deps.push(Dependency {
                     name: "uv".to_string(),
                     install_instructions: Some("pip install uv".to_string()),
+                    found_version: None,
+                    required_version: None,
                 });
             }
-        },
-        Language::Typescript | Language::Ts => {
-            // Check Node.js
-            if which("node").is_err() {
+            
+            // pip projects install with the pip of a virtual environment created by venv
+            if matches!(tool, Tool::Pip) && which(SYSTEM_PYTHON).is_ok() && !has_venv_module() {
+                missing_deps.push(Dependency {
+                    name: "Python venv module".to_string(),
+                    install_instructions: Some("apt install python3-venv (Debian and Ubuntu)".to_string()),
+                    found_version: None,
+                    required_version: None,
+                });
+            }
+            
+            // Check Poetry if tool is Poetry
+            if matches!(tool, Tool::Poetry) && which("poetry").is_err() {
                 missing_deps.push(Dependency {
-                    name: "Node.js 18+".to_string(),
-                    install_instructions: Some("https://nodejs.org/".to_string()),
+                    name: "poetry".to_string(),
+                    install_instructions: Some("pipx install poetry".to_string()),
+                    found_version: None,
+                    required_version: None,
                 });
             }
+        },
+        Language::Typescript | Language::Ts => {
+            // Check Node.js (Deno projects run without it)
+            if !matches!(tool, Tool::Deno) {
+                if which("node").is_err() {
+                    missing_deps.push(Dependency {
+                        name: "Node.js 18+".to_string(),
+                        install_instructions: Some("https://nodejs.org/".to_string()),
+                        found_version: None,
+                        required_version: None,
+                    });
+                } else if let Some(outdated) = check_minimum_version("Node.js", "node", MIN_NODE_VERSION, "https://nodejs.org/") {
+                    missing_deps.push(outdated);
+                }
+            }
             
             // Check package manager
             match tool {
-                Tool::Pnpm => {
-                    if which("pnpm").is_err() {
-                        missing_deps.push(Dependency {
-                            name: "pnpm".to_string(),
-                            install_instructions: Some("npm install -g pnpm".to_string()),
-                        });
-                    }
+                Tool::Pnpm if which("pnpm").is_err() => {
+                    missing_deps.push(Dependency {
+                        name: "pnpm".to_string(),
+                        install_instructions: Some("npm install -g pnpm".to_string()),
+                        found_version: None,
+                        required_version: None,
+                    });
+                },
+                Tool::Yarn if which("yarn").is_err() => {
+                    missing_deps.push(Dependency {
+                        name: "yarn".to_string(),
+                        install_instructions: Some("npm install -g yarn".to_string()),
+                        found_version: None,
+                        required_version: None,
+                    });
+                },
+                Tool::Npm if which("npm").is_err() => {
+                    missing_deps.push(Dependency {
+                        name: "npm".to_string(),
+                        install_instructions: Some("It comes with Node.js, please install Node.js".to_string()),
+                        found_version: None,
+                        required_version: None,
+                    });
                 },
-                Tool::Yarn => {
-                    if which("yarn").is_err() {
-                        missing_deps.push(Dependency {
-                            name: "yarn".to_string(),
-                            install_instructions: Some("npm install -g yarn".to_string()),
-                        });
-                    }
+                Tool::Bun if which("bun").is_err() => {
+                    missing_deps.push(Dependency {
+                        name: "bun".to_string(),
+                        install_instructions: Some("https://bun.sh".to_string()),
+                        found_version: None,
+                        required_version: None,
+                    });
                 },
-                Tool::Npm => {
-                    if which("npm").is_err() {
-                        missing_deps.push(Dependency {
-                            name: "npm".to_string(),
-                            install_instructions: Some("It comes with Node.js, please install Node.js".to_string()),
-                        });
-                    }
+                Tool::Deno if which("deno").is_err() => {
+                    missing_deps.push(Dependency {
+                        name: "deno".to_string(),
+                        install_instructions: Some("https://deno.com".to_string()),
+                        found_version: None,
+                        required_version: None,
+                    });
                 },
                 _ => {},
             }
         },
+        Language::Go | Language::Golang => {
+            // Check Go
+            if which("go").is_err() {
+                missing_deps.push(Dependency {
+                    name: "Go 1.23+".to_string(),
+                    install_instructions: Some("https://go.dev/dl/".to_string()),
+                    found_version: None,
+                    required_version: None,
+                });
+            }
+        },
+        Language::Rust => {
+            // Check Cargo
+            if which("cargo").is_err() {
+                missing_deps.push(Dependency {
+                    name: "Rust (cargo)".to_string(),
+                    install_instructions: Some("https://rustup.rs/".to_string()),
+                    found_version: None,
+                    required_version: None,
+                });
+            }
+        },
     }
     
     if missing_deps.is_empty() {
@@ -83,4 +169,153 @@ pub fn check_dependencies(language: &Language, tool: &Tool) -> Result<(), Vec<De
     } else {
         Err(missing_deps)
     }
-} 
\ No newline at end of file
+}
+
+/// Check that the selected package manager is installed and is the one the
+/// generator will actually use.
+///
+/// Unlike `check_dependencies`, a tool that does not apply to the language is an
+/// error here rather than being silently replaced (TypeScript falls back to npm,
+/// Python to uv).
+pub fn check_selected_tool(language: &Language, tool: &Tool) -> Result<()> {
+    let language_name = match language {
+        Language::Python | Language::Py => "Python",
+        Language::Typescript | Language::Ts => "TypeScript",
+        Language::Go | Language::Golang => "Go",
+        Language::Rust => "Rust",
+    };
+    let name = match tool {
+        Tool::GoMod => "go".to_string(),
+        _ => format!("{:?}", tool).to_lowercase(),
+    };
+    
+    if !tool.supports(language) {
+        bail!("{} cannot be used for {} projects", name, language_name);
+    }
+    // pip runs from the project's virtual environment, so only its interpreter needs to be installed
+    if matches!(tool, Tool::Pip) {
+        if !has_venv_module() {
+            bail!("The selected package manager pip needs {} with the venv module, which is not installed", SYSTEM_PYTHON);
+        }
+    } else if which(&name).is_err() {
+        bail!("The selected package manager {} is not installed", name);
+    }
+    
+    Ok(())
+}
+
+/// Whether the system Python can create virtual environments with pip in them; Debian and
+/// Ubuntu split `ensurepip` out of the standard library into the python3-venv package
+fn has_venv_module() -> bool {
+    Command::new(SYSTEM_PYTHON)
+        .args(["-c", "import ensurepip, venv"])
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Report `program` as outdated when its version is below `minimum`.
+///
+/// Versions that cannot be detected or parsed are not reported, so an unusual
+/// `--version` output never blocks generation.
+fn check_minimum_version(name: &str, program: &str, minimum: (u64, u64), install_instructions: &str) -> Option<Dependency> {
+    let output = detect_version(program)?;
+    let (found, version) = parse_version(&output)?;
+    if version >= minimum {
+        return None;
+    }
+    
+    Some(Dependency {
+        name: name.to_string(),
+        install_instructions: Some(install_instructions.to_string()),
+        found_version: Some(found),
+        required_version: Some(format!(">={}.{}", minimum.0, minimum.1)),
+    })
+}
+
+/// Parse the first version number in a `--version` output such as `v16.3.0` or `Python 3.9.7`
+fn parse_version(output: &str) -> Option<(String, (u64, u64))> {
+    let found = output
+        .split_whitespace()
+        .map(|word| word.trim_start_matches('v'))
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))?;
+    
+    let mut parts = found.split('.').map(|part| {
+        part.chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>()
+            .parse::<u64>()
+            .ok()
+    });
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+    
+    Some((found.to_string(), (major, minor)))
+}
+
+/// Detect the version of an installed program from its `--version` output
+pub fn detect_version(program: &str) -> Option<String> {
+    // Go has a `version` subcommand instead of a flag
+    let flag = if program == "go" { "version" } else { "--version" };
+    let output = Command::new(program).arg(flag).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if version.is_empty() {
+        None
+    } else {
+        Some(version)
+    }
+}
+
+/// Check whether the Node.js architecture matches the host machine.
+///
+/// Returns a warning message when they differ (e.g. an x64 Node running under
+/// Rosetta on Apple Silicon), since native modules would then be built for the
+/// wrong architecture.
+pub fn check_node_arch() -> Option<String> {
+    let output = Command::new("node")
+        .args(["-p", "process.arch"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let node_arch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let host_arch = host_node_arch()?;
+    
+    if node_arch == host_arch {
+        None
+    } else {
+        Some(format!(
+            "Node.js is built for {} but this machine is {}. Native modules may fail to install or load; consider installing a {} build of Node.js.",
+            node_arch, host_arch, host_arch
+        ))
+    }
+}
+
+/// Map the host architecture to the names used by Node's `process.arch`
+fn host_node_arch() -> Option<&'static str> {
+    // A translated process on Apple Silicon reports x86_64, so ask the OS directly
+    if cfg!(target_os = "macos") {
+        let translated = Command::new("sysctl")
+            .args(["-n", "sysctl.proc_translated"])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+            .unwrap_or(false);
+        if translated {
+            return Some("arm64");
+        }
+    }
+    
+    match std::env::consts::ARCH {
+        "x86_64" => Some("x64"),
+        "x86" => Some("ia32"),
+        "aarch64" => Some("arm64"),
+        "arm" => Some("arm"),
+        "powerpc64" => Some("ppc64"),
+        "s390x" => Some("s390x"),
+        _ => None,
+    }
+}
@@ -0,0 +1,95 @@
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use colored::Colorize;
+use dialoguer::{theme::ColorfulTheme, Input, Select};
+use std::io::{self, IsTerminal};
+use std::path::Path;
+
+use crate::{get_default_tool, Cli, Language, Tool, LANGUAGES};
+use super::naming::validate_project_name;
+use super::output::value_name;
+
+/// Prompt for the project name, language and tool, defaulting to the values given on the command line
+pub fn prompt_project(cli: &mut Cli) -> Result<()> {
+    if !io::stdin().is_terminal() {
+        bail!("A project name is required when mcpc is not run in a terminal");
+    }
+    
+    let theme = ColorfulTheme::default();
+    let existing_project = cli.deps_only || cli.print_next_steps_only;
+    // Only a new project needs a directory that does not exist yet (a dry run may target one)
+    let new_project = !existing_project && !cli.dry_run;
+    
+    let mut name_prompt = Input::<String>::with_theme(&theme)
+        .with_prompt("Project name")
+        .validate_with(|name: &String| check_name_input(name, new_project));
+    if let Some(name) = &cli.project_name {
+        name_prompt = name_prompt.with_initial_text(name);
+    }
+    cli.project_name = Some(name_prompt.interact_text()?.trim().to_string());
+    
+    // Existing projects are detected from their files instead
+    if existing_project {
+        return Ok(());
+    }
+    
+    let default_language = LANGUAGES
+        .iter()
+        .position(|(_, language)| language.same_as(&cli.language))
+        .unwrap_or(0);
+    let language_index = Select::with_theme(&theme)
+        .with_prompt("Language")
+        .items(&LANGUAGES.map(|(name, _)| name))
+        .default(default_language)
+        .interact()?;
+    cli.language = LANGUAGES[language_index].1.clone();
+    
+    // The package naming rules depend on the language, so the name is only checked against them now
+    let name = cli.project_name.clone().unwrap_or_default();
+    if let Err(e) = validate_project_name(&name, &cli.language) {
+        eprintln!("{} {:#}", "❌".red().bold(), e);
+        let language = cli.language.clone();
+        let name = Input::<String>::with_theme(&theme)
+            .with_prompt("Project name")
+            .with_initial_text(name)
+            .validate_with(|name: &String| {
+                check_name_input(name, new_project)?;
+                validate_project_name(name.trim(), &language).map_err(|e| e.to_string())
+            })
+            .interact_text()?;
+        cli.project_name = Some(name.trim().to_string());
+    }
+    
+    let tools = language_tools(&cli.language);
+    let tool_names: Vec<String> = tools.iter().map(value_name).collect();
+    let selected_tool = value_name(&cli.tool.clone().unwrap_or_else(|| get_default_tool(&cli.language)));
+    let tool_index = Select::with_theme(&theme)
+        .with_prompt("Package manager")
+        .items(&tool_names)
+        .default(tool_names.iter().position(|name| *name == selected_tool).unwrap_or(0))
+        .interact()?;
+    cli.tool = Some(tools[tool_index].clone());
+    
+    Ok(())
+}
+
+/// Check a project name entered in the wizard, before its language is known
+fn check_name_input(name: &str, new_project: bool) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("The project name cannot be empty".to_string());
+    }
+    if new_project && Path::new(name).exists() {
+        return Err(format!("Directory '{}' already exists", name));
+    }
+    
+    Ok(())
+}
+
+/// Package managers that can be selected for a language
+fn language_tools(language: &Language) -> Vec<Tool> {
+    Tool::value_variants().iter()
+        .filter(|tool| tool.supports(language))
+        .cloned()
+        .collect()
+}
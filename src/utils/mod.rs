@@ -1 +1,9 @@
-pub mod dependency_checker; 
\ No newline at end of file
+pub mod archive;
+pub mod dependency_checker;
+pub mod interactive;
+pub mod license;
+pub mod naming;
+pub mod openapi;
+pub mod output;
+pub mod project_detector;
+pub mod source;
\ No newline at end of file
@@ -0,0 +1,119 @@
+use anyhow::{bail, Result};
+
+use crate::generators::sanitize_server_name;
+use crate::Language;
+
+/// Longest package name the npm registry accepts
+const NPM_MAX_LENGTH: usize = 214;
+
+/// Names npm refuses regardless of their characters
+const NPM_RESERVED_NAMES: &[&str] = &["node_modules", "favicon.ico"];
+
+/// Checks that the project name is a valid package name for the language's registry:
+/// npm's rules for TypeScript projects, PEP 508's for Python ones
+pub fn validate_project_name(name: &str, language: &Language) -> Result<()> {
+    let (registry, name_problems): (&str, fn(&str) -> Vec<String>) = match language {
+        Language::Typescript | Language::Ts => ("npm package", npm_name_problems),
+        Language::Python | Language::Py => ("Python package", python_name_problems),
+        Language::Go | Language::Golang | Language::Rust => return Ok(()),
+    };
+    let problems = name_problems(name);
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    // Suggest the name sanitized like server names, if that is valid and actually different
+    let suggestion = sanitize_server_name(name).trim_start_matches('_').to_string();
+    let alternative = if suggestion != name && name_problems(&suggestion).is_empty() {
+        format!("Try '{}' instead", suggestion)
+    } else {
+        "Choose another name".to_string()
+    };
+    bail!(
+        "'{}' is not a valid {} name: {}. {}; --path lets the directory keep a different name",
+        name,
+        registry,
+        problems.join("; "),
+        alternative
+    )
+}
+
+/// What keeps `name` from being published to npm, if anything
+fn npm_name_problems(name: &str) -> Vec<String> {
+    if name.is_empty() {
+        return vec!["it is empty".to_string()];
+    }
+
+    let mut problems = Vec::new();
+    if name.len() > NPM_MAX_LENGTH {
+        problems.push(format!("it is longer than {} characters", NPM_MAX_LENGTH));
+    }
+    if NPM_RESERVED_NAMES.contains(&name) {
+        problems.push("it is reserved".to_string());
+    }
+
+    // Scoped names are `@scope/name`, with the same rules for both parts
+    let parts = match name.strip_prefix('@') {
+        Some(scoped) => match scoped.split_once('/') {
+            Some((scope, package)) => vec![scope, package],
+            None => {
+                problems.push("a scoped name needs a '/' between the scope and the package name".to_string());
+                vec![scoped]
+            }
+        },
+        None => vec![name],
+    };
+    if parts.iter().any(|part| part.starts_with('.') || part.starts_with('_')) {
+        problems.push("it starts with '.' or '_'".to_string());
+    }
+    if parts.iter().any(|part| part.chars().any(|c| c.is_uppercase())) {
+        problems.push("it contains uppercase letters".to_string());
+    }
+
+    let invalid = invalid_characters(&parts.concat(), |c| {
+        c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.') || c.is_uppercase()
+    });
+    if !invalid.is_empty() {
+        problems.push(format!("{} not URL-safe (use lowercase letters, digits, '-', '_' and '.')", invalid));
+    }
+
+    problems
+}
+
+/// What keeps `name` from being a valid PEP 508 project name, if anything
+fn python_name_problems(name: &str) -> Vec<String> {
+    if name.is_empty() {
+        return vec!["it is empty".to_string()];
+    }
+
+    let mut problems = Vec::new();
+    let invalid = invalid_characters(name, |c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !invalid.is_empty() {
+        problems.push(format!("{} not allowed (use letters, digits, '-', '_' and '.')", invalid));
+    }
+
+    let starts_and_ends_alphanumeric = name.chars().next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.chars().last().is_some_and(|c| c.is_ascii_alphanumeric());
+    if !starts_and_ends_alphanumeric {
+        problems.push("it must start and end with a letter or digit".to_string());
+    }
+
+    problems
+}
+
+/// Lists the distinct characters of `name` that `is_valid` rejects, e.g. `' ' and '!' are`
+fn invalid_characters(name: &str, is_valid: impl Fn(char) -> bool) -> String {
+    let mut invalid: Vec<String> = Vec::new();
+    for c in name.chars().filter(|&c| !is_valid(c)) {
+        let quoted = format!("'{}'", c);
+        if !invalid.contains(&quoted) {
+            invalid.push(quoted);
+        }
+    }
+
+    match invalid.as_slice() {
+        [] => String::new(),
+        [only] => format!("{} is", only),
+        [rest @ .., last] => format!("{} and {} are", rest.join(", "), last),
+    }
+}
@@ -0,0 +1,217 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use super::source::read_source;
+
+/// Base URL used when the spec does not declare an absolute server URL
+pub const FALLBACK_API_BASE: &str = "http://localhost:8080";
+
+/// GET operations extracted from an OpenAPI spec
+#[derive(Debug, Clone)]
+pub struct OpenApiTools {
+    /// Base URL the generated tools call
+    pub base_url: String,
+    /// Operations that become MCP tools
+    pub operations: Vec<ApiOperation>,
+}
+
+impl OpenApiTools {
+    /// Markdown list entries documenting the generated tools in a README
+    pub fn readme_entries(&self) -> String {
+        self.operations.iter()
+            .map(|operation| {
+                let parameters = if operation.parameters.is_empty() {
+                    "none".to_string()
+                } else {
+                    operation.parameters.iter()
+                        .map(|parameter| format!("`{}`", parameter.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                format!("- **{}**: {}\n  - Parameters: {}\n\n", operation.name, operation.description, parameters)
+            })
+            .collect()
+    }
+}
+
+/// A single GET operation exposed as an MCP tool
+#[derive(Debug, Clone)]
+pub struct ApiOperation {
+    /// Tool name derived from the operationId or the path
+    pub name: String,
+    /// Human readable description of the operation
+    pub description: String,
+    /// Path template, e.g. `/pets/{petId}`
+    pub path: String,
+    /// Path and query parameters
+    pub parameters: Vec<ApiParameter>,
+}
+
+/// A path or query parameter of an operation
+#[derive(Debug, Clone)]
+pub struct ApiParameter {
+    /// Parameter name as it appears in the spec
+    pub name: String,
+    /// Whether the parameter is part of the path rather than the query string
+    pub in_path: bool,
+    pub required: bool,
+    pub kind: ParamKind,
+    pub description: Option<String>,
+}
+
+/// Simple parameter types supported by the generated tools
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamKind {
+    String,
+    Integer,
+    Number,
+    Boolean,
+}
+
+#[derive(Deserialize)]
+struct Spec {
+    #[serde(default)]
+    servers: Vec<Server>,
+    #[serde(default)]
+    paths: BTreeMap<String, PathItem>,
+}
+
+#[derive(Deserialize)]
+struct Server {
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct PathItem {
+    get: Option<Operation>,
+    #[serde(default)]
+    parameters: Vec<RawParameter>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Operation {
+    operation_id: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    parameters: Vec<RawParameter>,
+}
+
+#[derive(Deserialize)]
+struct RawParameter {
+    // Referenced parameters ($ref) have neither a name nor a location and are skipped
+    name: Option<String>,
+    #[serde(rename = "in")]
+    location: Option<String>,
+    #[serde(default)]
+    required: bool,
+    description: Option<String>,
+    schema: Option<RawSchema>,
+}
+
+#[derive(Deserialize)]
+struct RawSchema {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+}
+
+/// Load an OpenAPI spec (YAML or JSON) from a file path or an http(s) URL
+pub fn load_openapi_tools(source: &str) -> Result<OpenApiTools> {
+    let content = read_source(source, "OpenAPI spec")?;
+
+    // JSON is valid YAML, so one parser covers both formats
+    let spec: Spec = serde_yaml::from_str(&content)
+        .context(format!("Failed to parse OpenAPI spec: {}", source))?;
+
+    let base_url = spec.servers.first()
+        .map(|server| server.url.trim_end_matches('/').to_string())
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+        .unwrap_or_else(|| FALLBACK_API_BASE.to_string());
+
+    let mut operations = Vec::new();
+    for (path, item) in &spec.paths {
+        let Some(operation) = &item.get else { continue };
+
+        let mut parameters: Vec<ApiParameter> = Vec::new();
+        for raw in item.parameters.iter().chain(&operation.parameters) {
+            let Some(parameter) = convert_parameter(raw) else { continue };
+            // Operation-level parameters override path-level ones with the same name
+            parameters.retain(|existing| existing.name != parameter.name);
+            parameters.push(parameter);
+        }
+
+        let name = operation.operation_id.as_deref()
+            .map(tool_name)
+            .unwrap_or_else(|| tool_name(&format!("get {}", path)));
+        let description = operation.summary.clone()
+            .or_else(|| operation.description.clone())
+            .unwrap_or_else(|| format!("GET {}", path));
+
+        operations.push(ApiOperation {
+            name,
+            description: description.lines().next().unwrap_or_default().to_string(),
+            path: path.clone(),
+            parameters,
+        });
+    }
+
+    if operations.is_empty() {
+        bail!("OpenAPI spec {} has no GET operations to turn into tools", source);
+    }
+
+    Ok(OpenApiTools { base_url, operations })
+}
+
+/// Convert a raw spec parameter, skipping $refs and header/cookie parameters
+fn convert_parameter(raw: &RawParameter) -> Option<ApiParameter> {
+    let name = raw.name.clone()?;
+    let in_path = match raw.location.as_deref()? {
+        "path" => true,
+        "query" => false,
+        _ => return None,
+    };
+    let kind = match raw.schema.as_ref().and_then(|schema| schema.kind.as_deref()) {
+        Some("integer") => ParamKind::Integer,
+        Some("number") => ParamKind::Number,
+        Some("boolean") => ParamKind::Boolean,
+        _ => ParamKind::String,
+    };
+
+    Some(ApiParameter {
+        name,
+        in_path,
+        // Path parameters are always required
+        required: raw.required || in_path,
+        kind,
+        description: raw.description.clone(),
+    })
+}
+
+/// Turn an operationId or path into a snake_case identifier usable as a tool name
+pub fn tool_name(raw: &str) -> String {
+    let mut name = String::new();
+    let mut previous_lower = false;
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && previous_lower {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+            previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        } else {
+            if !name.is_empty() && !name.ends_with('_') {
+                name.push('_');
+            }
+            previous_lower = false;
+        }
+    }
+
+    let name = name.trim_end_matches('_').to_string();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("op_{}", name)
+    } else {
+        name
+    }
+}
@@ -0,0 +1,90 @@
+use clap::ValueEnum;
+use std::env;
+use std::io::{self, IsTerminal};
+
+use crate::{get_default_tool, ColorChoice, Language, Tool, LANGUAGES};
+
+/// Environment variables set by common CI providers
+const CI_ENV_VARS: &[&str] = &["CI", "GITHUB_ACTIONS", "GITLAB_CI", "BUILDKITE", "CIRCLECI", "TF_BUILD"];
+
+/// Apply the requested color mode to all colored terminal output
+pub fn configure_color(choice: &ColorChoice) {
+    let enabled = match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            env::var_os("NO_COLOR").is_none() && !is_ci() && io::stdout().is_terminal()
+        },
+    };
+    
+    colored::control::set_override(enabled);
+}
+
+/// Name of an enum value as it is written on the command line
+pub fn value_name<T: ValueEnum>(value: &T) -> String {
+    value.to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+/// Print the command-line names of an enum's values, one per line or as a JSON array
+pub fn print_value_names<T: ValueEnum>(json: bool) {
+    let names: Vec<String> = T::value_variants().iter().map(value_name).collect();
+    
+    if json {
+        println!("{}", serde_json::Value::from(names));
+    } else {
+        for name in names {
+            println!("{}", name);
+        }
+    }
+}
+
+/// Print each language with its aliases, the tools managing it and its default tool,
+/// as an aligned table or as a JSON array of objects
+pub fn print_language_table(json: bool) {
+    let rows: Vec<(String, Vec<String>, Vec<String>, String)> = LANGUAGES.iter()
+        .map(|(name, language)| {
+            let aliases = Language::value_variants().iter()
+                .filter(|alias| alias.same_as(language) && value_name(*alias) != *name)
+                .map(value_name)
+                .collect();
+            let tools = Tool::value_variants().iter()
+                .filter(|tool| tool.supports(language))
+                .map(value_name)
+                .collect();
+            (name.to_string(), aliases, tools, value_name(&get_default_tool(language)))
+        })
+        .collect();
+    
+    if json {
+        let rows: Vec<_> = rows.into_iter()
+            .map(|(language, aliases, tools, default_tool)| serde_json::json!({
+                "language": language,
+                "aliases": aliases,
+                "tools": tools,
+                "default_tool": default_tool,
+            }))
+            .collect();
+        println!("{}", serde_json::Value::from(rows));
+        return;
+    }
+    
+    let rows: Vec<[String; 4]> = rows.into_iter()
+        .map(|(language, aliases, tools, default_tool)| [language, aliases.join(", "), tools.join(", "), default_tool])
+        .collect();
+    let header = ["LANGUAGE", "ALIASES", "TOOLS", "DEFAULT TOOL"].map(String::from);
+    let widths: Vec<usize> = (0..3)
+        .map(|column| rows.iter().chain([&header]).map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+    for row in [&header].into_iter().chain(&rows) {
+        println!("{:<w0$}  {:<w1$}  {:<w2$}  {}", row[0], row[1], row[2], row[3], w0 = widths[0], w1 = widths[1], w2 = widths[2]);
+    }
+}
+
+/// Detect whether mcpc is running inside a CI environment
+pub fn is_ci() -> bool {
+    CI_ENV_VARS.iter().any(|var| {
+        env::var(var).is_ok_and(|value| !value.is_empty() && value != "false" && value != "0")
+    })
+}
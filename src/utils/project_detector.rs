@@ -0,0 +1,64 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{Language, Tool};
+
+/// Files that operating systems and file managers drop into directories on their own
+pub const JUNK_FILES: &[&str] = &[".DS_Store", ".localized", ".directory", "Thumbs.db", "ehthumbs.db", "desktop.ini"];
+
+/// Whether `path` is a directory holding nothing but `JUNK_FILES`
+pub fn is_effectively_empty(path: &Path) -> bool {
+    let Ok(mut entries) = fs::read_dir(path) else {
+        return false;
+    };
+    entries.all(|entry| {
+        entry.is_ok_and(|entry| JUNK_FILES.iter().any(|junk| entry.file_name() == *junk))
+    })
+}
+
+/// Detect the language of an existing project from the files it contains
+pub fn detect_language(project_path: &Path) -> Option<Language> {
+    if project_path.join("package.json").exists() || project_path.join("deno.json").exists() {
+        Some(Language::Typescript)
+    } else if project_path.join("go.mod").exists() {
+        Some(Language::Go)
+    } else if project_path.join("Cargo.toml").exists() {
+        Some(Language::Rust)
+    } else if project_path.join("pyproject.toml").exists()
+        || project_path.join("requirements.txt").exists()
+    {
+        Some(Language::Python)
+    } else {
+        None
+    }
+}
+
+/// Detect the package manager of an existing project from its lockfiles and config
+pub fn detect_tool(project_path: &Path, language: &Language) -> Option<Tool> {
+    match language {
+        Language::Typescript | Language::Ts => {
+            let lockfiles = [
+                ("deno.json", Tool::Deno),
+                ("pnpm-lock.yaml", Tool::Pnpm),
+                ("yarn.lock", Tool::Yarn),
+                ("bun.lock", Tool::Bun),
+                ("bun.lockb", Tool::Bun),
+                ("package-lock.json", Tool::Npm),
+            ];
+            lockfiles.into_iter()
+                .find(|(file, _)| project_path.join(file).exists())
+                .map(|(_, tool)| tool)
+        },
+        Language::Python | Language::Py => {
+            if project_path.join("uv.lock").exists() {
+                Some(Tool::Uv)
+            } else if project_path.join("poetry.lock").exists() || project_path.join("poetry.toml").exists() {
+                Some(Tool::Poetry)
+            } else {
+                Some(Tool::Uv)
+            }
+        },
+        Language::Go | Language::Golang => Some(Tool::GoMod),
+        Language::Rust => Some(Tool::Cargo),
+    }
+}
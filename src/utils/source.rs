@@ -0,0 +1,14 @@
+use anyhow::{Context, Result};
+use std::fs;
+
+/// Read a text file from a local path or an http(s) URL
+pub fn read_source(source: &str, description: &str) -> Result<String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        reqwest::blocking::get(source)
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .context(format!("Failed to download {}: {}", description, source))
+    } else {
+        fs::read_to_string(source).context(format!("Failed to read {}: {}", description, source))
+    }
+}